@@ -1,23 +1,36 @@
-use std::fs;
+use std::{cell::RefCell, fs, rc::Rc};
 
 use pyret_file::{graph::PyretGraph, PyretFile};
 
+/// The files of a graph, shared with the output handler so that it can render
+/// the tests that failed in them.
+pub type SharedFiles = Rc<RefCell<Vec<Rc<PyretFile>>>>;
+
 #[derive(Default)]
 pub struct FsGraph {
-    pub files: Vec<PyretFile>,
+    pub files: Vec<Rc<PyretFile>>,
+    pub shared: SharedFiles,
 }
 
-impl PyretGraph for FsGraph {
-    fn register(&mut self, name: &str) -> usize {
+impl FsGraph {
+    pub fn push(&mut self, file: PyretFile) -> usize {
         let file_id = self.files.len();
+        let file = Rc::new(file);
 
-        self.files.push(PyretFile::new(
-            format!("file://{name}").into_boxed_str(),
-            fs::read_to_string(name).unwrap().into_boxed_str(),
-        ));
+        self.shared.borrow_mut().push(Rc::clone(&file));
+        self.files.push(file);
 
         file_id
     }
+}
+
+impl PyretGraph for FsGraph {
+    fn register(&mut self, name: &str) -> usize {
+        self.push(PyretFile::new(
+            format!("file://{name}").into_boxed_str(),
+            fs::read_to_string(name).unwrap().into_boxed_str(),
+        ))
+    }
 
     fn get(&self, file_id: usize) -> &PyretFile {
         self.files.get(file_id).unwrap()
@@ -1,39 +1,95 @@
 mod dir;
 mod graph;
+mod test;
 
-use std::{fs};
+use std::{fs, panic, path::PathBuf, process, rc::Rc, thread};
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use crossterm::style::{Color, Stylize};
-use graph::FsGraph;
+use graph::{FsGraph, SharedFiles};
 use pyret_error::{
     miette::{self, IntoDiagnostic},
     PyretFile,
 };
 use pyret_interpreter::{
-    io::Output,
-    value::{PyretValueKind},
-    Interpreter, PyretGraph,
+    io::Output, report::TestFailureReport, value::PyretValueKind, CheckMode, Interpreter,
+    PyretGraph, TestResult,
 };
 use pyret_number::PyretNumber;
 use rustyline::{error::ReadlineError, DefaultEditor};
 
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, args_conflicts_with_subcommands = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
     program: Option<String>,
+    /// Which test blocks to run: none, main or all
+    #[arg(long, default_value = "all")]
+    checks: CheckMode,
+    /// Checks the types of the program before running it
+    #[arg(long)]
+    type_check: bool,
 }
 
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Runs the tests of every `.arr` file under a directory
+    Test {
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Only runs the check blocks whose label contains this
+        #[arg(long)]
+        filter: Option<String>,
+        #[arg(long, value_enum, default_value_t = test::Format::Pretty)]
+        format: test::Format,
+        /// Checks the types of each file before running its tests
+        #[arg(long)]
+        type_check: bool,
+    },
+}
+
+/// The size of the stack of the thread running the interpreter, which leaves
+/// room for the applications of a program to nest
+/// [`MAX_CALL_DEPTH`](pyret_interpreter::MAX_CALL_DEPTH) deep.
+const STACK_SIZE: usize = 1 << 30;
+
 fn main() -> miette::Result<()> {
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(run)
+        .into_diagnostic()?
+        .join()
+        .unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+fn run() -> miette::Result<()> {
     let args = Args::parse();
 
+    if let Some(Command::Test {
+        path,
+        filter,
+        format,
+        type_check,
+    }) = args.command
+    {
+        if !test::run(&path, filter, format, type_check) {
+            process::exit(1);
+        }
+
+        return Ok(());
+    }
+
     let mut interpreter = Interpreter::new(FsGraph::default());
 
-    interpreter
-        .context
-        .borrow_mut()
-        .io
-        .read(Box::new(handle_output));
+    interpreter.check_mode = args.checks;
+    interpreter.type_check = args.type_check;
+
+    interpreter.context.borrow_mut().io.read(Box::new({
+        let files = Rc::clone(&interpreter.graph.shared);
+
+        move |output| handle_output(output, &files)
+    }));
 
     if let Err(error) = interpreter.import_trove("global") {
         eprintln!("{error:?}");
@@ -67,10 +123,8 @@ fn main() -> miette::Result<()> {
                 Ok(line) => {
                     rl.add_history_entry(line.as_str()).into_diagnostic()?;
 
-                    let file_id = interpreter.graph.files.len();
-
-                    interpreter.graph.files.push(PyretFile::new(
-                        format!("repl://{file_id}").into_boxed_str(),
+                    let file_id = interpreter.graph.push(PyretFile::new(
+                        format!("repl://{}", interpreter.graph.files.len()).into_boxed_str(),
                         line.into_boxed_str(),
                     ));
 
@@ -104,7 +158,11 @@ fn print_values(interpreter: &mut Interpreter<FsGraph>, file_id: usize) {
                         },
                         PyretValueKind::String(_) => Color::Cyan,
                         PyretValueKind::Boolean(_) => Color::DarkMagenta,
-                        PyretValueKind::Function(_) => Color::Grey,
+                        PyretValueKind::Function(_) | PyretValueKind::Method(_) => Color::Grey,
+                        PyretValueKind::Data(_)
+                        | PyretValueKind::Object(_)
+                        | PyretValueKind::Tuple(_)
+                        | PyretValueKind::Ref(_) => Color::Green,
                         PyretValueKind::Nothing => unreachable!(),
                     };
 
@@ -120,7 +178,7 @@ fn print_values(interpreter: &mut Interpreter<FsGraph>, file_id: usize) {
     }
 }
 
-fn handle_output(output: Output) {
+fn handle_output(output: Output, files: &SharedFiles) {
     match output {
         Output::Display(value) => {
             if *value.kind != PyretValueKind::Nothing {
@@ -135,65 +193,81 @@ fn handle_output(output: Output) {
                 .collect::<Vec<_>>()
                 .join("\n")
         ),
-        Output::Test { label, results } => {
-            let passed = results.iter().filter(|result| result.passed).count();
-            let total = results.len();
+        Output::Test {
+            label,
+            file_id,
+            results,
+        } => {
+            let file = Rc::clone(&files.borrow()[file_id]);
+
+            print_test_block(label.as_deref(), &results, &file);
+        }
+    }
+}
 
-            let all_passed = passed == total;
+/// Prints the results of a test block, rendering the tests that failed from
+/// the file they are in.
+fn print_test_block(label: Option<&str>, results: &[TestResult], file: &PyretFile) {
+    let passed = results.iter().filter(|result| result.passed()).count();
+    let total = results.len();
 
-            let color = if all_passed {
-                Color::Green
-            } else {
-                Color::Yellow
-            };
+    let all_passed = passed == total;
 
-            if let Some(label) = label {
-                println!("{}", label.underlined().with(color));
-            }
+    let color = if all_passed {
+        Color::Green
+    } else {
+        Color::Yellow
+    };
 
-            if all_passed {
-                if total == 1 {
-                    println!("{}", "The test in this block passed.".italic());
-                } else {
-                    println!(
-                        "{}",
-                        format!("All {total} tests in this block passed.").italic()
-                    );
-                }
-            } else if passed == 0 {
-                if total == 1 {
-                    eprintln!("{}", "The test in this block failed.".italic());
-                } else {
-                    eprintln!(
-                        "{}",
-                        format!("All {total} tests in this block failed.").italic()
-                    );
-                }
-            } else {
-                eprintln!("{passed} out of {total} test passed in this block");
-            }
+    if let Some(label) = label {
+        println!("{}", label.underlined().with(color));
+    }
 
-            for (i, result) in results.iter().enumerate() {
-                let i = i + 1;
-
-                if result.passed {
-                    println!(
-                        "  {} {}",
-                        format!("Test {i}:").underlined().green(),
-                        "Passed".green()
-                    );
-                } else {
-                    eprintln!(
-                        "  {} {}",
-                        format!("Test {i}:").underlined().yellow(),
-                        "Failed".yellow()
-                    );
-                }
-            }
+    if all_passed {
+        if total == 1 {
+            println!("{}", "The test in this block passed.".italic());
+        } else {
+            println!(
+                "{}",
+                format!("All {total} tests in this block passed.").italic()
+            );
+        }
+    } else if passed == 0 {
+        if total == 1 {
+            eprintln!("{}", "The test in this block failed.".italic());
+        } else {
+            eprintln!(
+                "{}",
+                format!("All {total} tests in this block failed.").italic()
+            );
+        }
+    } else {
+        eprintln!("{passed} out of {total} test passed in this block");
+    }
 
-            println!();
+    for (i, result) in results.iter().enumerate() {
+        let i = i + 1;
+
+        if result.passed() {
+            println!(
+                "  {} {}",
+                format!("Test {i}:").underlined().green(),
+                "Passed".green()
+            );
+        } else {
+            eprintln!(
+                "  {} {}",
+                format!("Test {i}:").underlined().yellow(),
+                "Failed".yellow()
+            );
+
+            if let Some(report) = TestFailureReport::new(result.clone(), &file.source) {
+                eprintln!("{:?}", report.into_report(file));
+            }
         }
     }
+
+    println!();
 }
 
 // use std::fs;
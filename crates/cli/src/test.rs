@@ -0,0 +1,401 @@
+use std::{
+    fmt::Write,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use clap::ValueEnum;
+use crossterm::style::Stylize;
+use pyret_error::{PyretError, PyretErrorKind, PyretFile};
+use pyret_interpreter::{
+    report::{TestBlock, TestReport},
+    Interpreter, PyretGraph, TestResult,
+};
+
+use crate::{graph::FsGraph, handle_output, print_test_block};
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Reports each file as it runs, followed by a summary.
+    Pretty,
+    /// The Test Anything Protocol.
+    Tap,
+    /// JUnit XML, as read by most CI services.
+    Junit,
+}
+
+/// The report of a single file, along with its source, which the spans of the
+/// tests point into.
+struct FileReport {
+    path: String,
+    source: Box<str>,
+    report: TestReport,
+}
+
+impl FileReport {
+    /// The tests of the file, along with the block they are in.
+    fn tests(&self) -> impl Iterator<Item = (&TestBlock, usize, &TestResult)> {
+        self.report.blocks.iter().flat_map(|block| {
+            block
+                .results
+                .iter()
+                .enumerate()
+                .map(move |(index, result)| (block, index + 1, result))
+        })
+    }
+
+    fn total(&self) -> usize {
+        self.report.passed + self.report.failed
+    }
+
+    /// Names a test by its file, line, block and position in the block.
+    fn describe(&self, block: &TestBlock, index: usize, result: &TestResult) -> String {
+        let line = line_of(&self.source, *result.test_span.start());
+
+        match &block.label {
+            Some(label) => format!("{}:{line} {label} test {index}", self.path),
+            None => format!("{}:{line} test {index}", self.path),
+        }
+    }
+}
+
+/// Runs the tests of every `.arr` file under `path`, and returns whether they
+/// all passed.
+pub fn run(path: &Path, filter: Option<String>, format: Format, type_check: bool) -> bool {
+    let mut paths = Vec::new();
+
+    collect_files(path, &mut paths);
+    paths.sort();
+
+    let files = paths
+        .iter()
+        .map(|file| {
+            let name = file.strip_prefix(path).unwrap_or(file);
+            let name = if name.as_os_str().is_empty() {
+                file
+            } else {
+                name
+            };
+
+            run_file(
+                file,
+                name.to_string_lossy().into_owned(),
+                filter.as_deref(),
+                format,
+                type_check,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    match format {
+        Format::Pretty => print_summary(&files),
+        Format::Tap => print!("{}", tap(&files)),
+        Format::Junit => print!("{}", junit(&files)),
+    }
+
+    files.iter().all(|file| file.report.succeeded())
+}
+
+fn collect_files(path: &Path, paths: &mut Vec<PathBuf>) {
+    if path.is_file() {
+        if path.extension().is_some_and(|extension| extension == "arr") {
+            paths.push(path.to_path_buf());
+        }
+
+        return;
+    }
+
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.flatten() {
+            collect_files(&entry.path(), paths);
+        }
+    }
+}
+
+/// Runs the tests of a file, reporting a panic as an error of the file, so
+/// that the rest of the files still run.
+fn run_file(
+    path: &Path,
+    name: String,
+    filter: Option<&str>,
+    format: Format,
+    type_check: bool,
+) -> FileReport {
+    if format == Format::Pretty {
+        println!("{}", name.as_str().bold());
+    }
+
+    let mut interpreter = Interpreter::new(FsGraph::default());
+
+    interpreter.test_filter = filter.map(Box::from);
+    interpreter.type_check = type_check;
+
+    interpreter.context.borrow_mut().io.read(Box::new({
+        let files = Rc::clone(&interpreter.graph.shared);
+
+        move |output| {
+            if format == Format::Pretty {
+                handle_output(output, &files);
+            }
+        }
+    }));
+
+    let run = panic::catch_unwind(AssertUnwindSafe(|| {
+        if let Err(error) = interpreter.import_trove("global") {
+            return TestReport {
+                errors: Box::new([PyretError::new(error, 0)]),
+                ..TestReport::default()
+            };
+        }
+
+        let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        let file_id = interpreter.graph.register(&canonical.to_string_lossy());
+
+        interpreter.run_tests(file_id)
+    }));
+
+    let report = run.unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_owned())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+
+        TestReport {
+            errors: Box::new([PyretError::new(
+                PyretErrorKind::RaiseRuntime(
+                    format!("the interpreter panicked: {message}").into_boxed_str(),
+                ),
+                0,
+            )]),
+            ..TestReport::default()
+        }
+    });
+
+    let file = interpreter.graph.files.first().map(Rc::clone);
+
+    if format == Format::Pretty {
+        print_report(&report, file.as_deref(), &interpreter.graph);
+    }
+
+    FileReport {
+        path: name,
+        source: file.map(|file| file.source.clone()).unwrap_or_default(),
+        report,
+    }
+}
+
+/// Prints the blocks of a file and the errors raised outside of them.
+fn print_report(report: &TestReport, file: Option<&PyretFile>, graph: &FsGraph) {
+    if let Some(file) = file {
+        for block in &report.blocks {
+            print_test_block(block.label.as_deref(), &block.results, file);
+        }
+    }
+
+    for error in &report.errors {
+        if graph.files.is_empty() {
+            eprintln!("{}", error.kind());
+        } else {
+            eprintln!("{:?}", error.clone().into_report(graph));
+        }
+    }
+}
+
+fn line_of(source: &str, position: usize) -> usize {
+    source[..position.min(source.len())].matches('\n').count() + 1
+}
+
+fn print_summary(files: &[FileReport]) {
+    let total = files.iter().map(FileReport::total).sum::<usize>();
+    let failed = files.iter().map(|file| file.report.failed).sum::<usize>();
+    let errors = files
+        .iter()
+        .map(|file| file.report.errors.len())
+        .sum::<usize>();
+
+    let summary = format!(
+        "{} of {total} tests passed in {} files, with {errors} errors outside of tests",
+        total - failed,
+        files.len()
+    );
+
+    if failed == 0 && errors == 0 {
+        println!("{}", summary.green());
+    } else {
+        eprintln!("{}", summary.yellow());
+
+        for file in files {
+            for (block, index, result) in file.tests().filter(|(.., result)| !result.passed()) {
+                eprintln!(
+                    "  {} {}",
+                    "Failed:".yellow(),
+                    file.describe(block, index, result)
+                );
+            }
+
+            for error in &file.report.errors {
+                eprintln!("  {} {}: {}", "Error:".red(), file.path, error.kind());
+            }
+        }
+    }
+}
+
+fn tap(files: &[FileReport]) -> String {
+    let total = files
+        .iter()
+        .map(|file| file.total() + file.report.errors.len())
+        .sum::<usize>();
+
+    let mut output = format!("TAP version 13\n1..{total}\n");
+    let mut number = 0;
+
+    for file in files {
+        for (block, index, result) in file.tests() {
+            number += 1;
+
+            let name = file.describe(block, index, result);
+
+            match &result.failure {
+                None => writeln!(output, "ok {number} - {name}").unwrap(),
+                Some(failure) => {
+                    writeln!(output, "not ok {number} - {name}").unwrap();
+                    writeln!(
+                        output,
+                        "  ---\n  message: {}\n  ...",
+                        yaml_string(&failure.to_string())
+                    )
+                    .unwrap();
+                }
+            }
+        }
+
+        for error in &file.report.errors {
+            number += 1;
+
+            writeln!(output, "not ok {number} - {}", file.path).unwrap();
+            writeln!(
+                output,
+                "  ---\n  message: {}\n  ...",
+                yaml_string(&error.kind().to_string())
+            )
+            .unwrap();
+        }
+    }
+
+    output
+}
+
+/// Quotes text as a YAML double-quoted string, for the diagnostics of TAP.
+fn yaml_string(text: &str) -> String {
+    let mut quoted = String::from('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => write!(quoted, "\\u{:04x}", u32::from(c)).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+
+    quoted
+}
+
+fn junit(files: &[FileReport]) -> String {
+    let total = files.iter().map(FileReport::total).sum::<usize>();
+    let failed = files.iter().map(|file| file.report.failed).sum::<usize>();
+    let errors = files
+        .iter()
+        .map(|file| file.report.errors.len())
+        .sum::<usize>();
+
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+    writeln!(
+        output,
+        "<testsuites tests=\"{total}\" failures=\"{failed}\" errors=\"{errors}\">"
+    )
+    .unwrap();
+
+    for file in files {
+        let path = escape(&file.path);
+
+        writeln!(
+            output,
+            "  <testsuite name=\"{path}\" tests=\"{}\" failures=\"{}\" errors=\"{}\">",
+            file.total(),
+            file.report.failed,
+            file.report.errors.len()
+        )
+        .unwrap();
+
+        for (block, index, result) in file.tests() {
+            let name = escape(&match &block.label {
+                Some(label) => format!("{label} test {index}"),
+                None => format!("test {index}"),
+            });
+
+            match &result.failure {
+                None => {
+                    writeln!(
+                        output,
+                        "    <testcase classname=\"{path}\" name=\"{name}\"/>"
+                    )
+                    .unwrap();
+                }
+                Some(failure) => {
+                    writeln!(
+                        output,
+                        "    <testcase classname=\"{path}\" name=\"{name}\">"
+                    )
+                    .unwrap();
+                    writeln!(
+                        output,
+                        "      <failure message=\"{}\">{path}:{}</failure>",
+                        escape(&failure.to_string()),
+                        line_of(&file.source, *result.test_span.start())
+                    )
+                    .unwrap();
+                    writeln!(output, "    </testcase>").unwrap();
+                }
+            }
+        }
+
+        for error in &file.report.errors {
+            writeln!(
+                output,
+                "    <testcase classname=\"{path}\" name=\"{path}\">"
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "      <error message=\"{}\"/>",
+                escape(&error.kind().to_string())
+            )
+            .unwrap();
+            writeln!(output, "    </testcase>").unwrap();
+        }
+
+        writeln!(output, "  </testsuite>").unwrap();
+    }
+
+    writeln!(output, "</testsuites>").unwrap();
+
+    output
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
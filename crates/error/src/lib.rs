@@ -8,7 +8,7 @@ pub use token::SerializedToken;
 
 pub type PyretResult<T> = Result<T, PyretErrorKind>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PyretError {
     kind: PyretErrorKind,
     file_id: usize,
@@ -20,6 +20,21 @@ impl PyretError {
         Self { kind, file_id }
     }
 
+    #[must_use]
+    pub const fn kind(&self) -> &PyretErrorKind {
+        &self.kind
+    }
+
+    #[must_use]
+    pub fn into_kind(self) -> PyretErrorKind {
+        self.kind
+    }
+
+    #[must_use]
+    pub const fn file_id(&self) -> usize {
+        self.file_id
+    }
+
     pub fn into_report(self, files: &impl PyretGraph) -> Report {
         let file = files.get(self.file_id);
 
@@ -49,7 +64,7 @@ impl PyretError {
 }
 
 #[non_exhaustive]
-#[derive(Error, Diagnostic, Debug)]
+#[derive(Error, Diagnostic, Debug, Clone)]
 pub enum PyretErrorKind {
     #[error("Pyret didn't understand your program")]
     #[diagnostic(help(
@@ -72,6 +87,73 @@ pub enum PyretErrorKind {
         position: usize,
     },
 
+    #[error("Pyret expected {blame} to satisfy its annotation")]
+    ContractViolation {
+        blame: Box<str>,
+        value: Box<str>,
+        #[label("this evaluated to {value}")]
+        span: SourceSpan,
+        #[label("the annotation")]
+        annotation: SourceSpan,
+    },
+
+    #[error("Pyret expected argument {position} of {function} to satisfy its annotation")]
+    ArgumentTypeMismatch {
+        function: Box<str>,
+        position: usize,
+        value: Box<str>,
+        #[label("this evaluated to {value}")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret expected {function} to be applied to {expected} arguments, but found {found}")]
+    ArityMismatch {
+        function: Box<str>,
+        expected: usize,
+        found: usize,
+        #[label("this application passes {found} arguments")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret found an assignment to {ident}, but it was not declared with var")]
+    AssignToNonVariable {
+        ident: Box<str>,
+        #[label("only var bindings can be assigned to")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret found this block ends with a declaration instead of an expression")]
+    BlockEndsInDeclaration {
+        #[label("the last statement of a block must result in a value")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret found this cases branch binds {found} fields, but {variant} has {expected}")]
+    CasesArityMismatch {
+        variant: Box<str>,
+        expected: usize,
+        found: usize,
+        #[label("this branch matches {variant}")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret found this cases branch names {ident}, which is not a variant of {type_name}")]
+    CasesUnknownVariant {
+        ident: Box<str>,
+        type_name: Box<str>,
+        #[label("{ident} is not a variant of {type_name}")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret found this declaration of {ident} conflicts with an earlier declaration of the same name")]
+    DuplicateDeclaration {
+        ident: Box<str>,
+        #[label("this is declared again")]
+        span: SourceSpan,
+        #[label("it was first declared here")]
+        previous: Option<SourceSpan>,
+    },
+
     #[error("Pyret found an empty block")]
     EmptyBlock {
         #[label]
@@ -84,18 +166,147 @@ pub enum PyretErrorKind {
         left: SerializedToken,
     },
 
+    #[error("Pyret could not find the field {field} on the left side of this lookup")]
+    FieldNotFound {
+        field: Box<str>,
+        #[label("this field was not found")]
+        property: SourceSpan,
+    },
+
+    #[error("Pyret expected a name to bind in this tuple binding")]
+    ExpectedBinding {
+        #[label("this is not a name or a tuple of names")]
+        span: SourceSpan,
+    },
+
     #[error("Pyret found evaluating this function application expression errored")]
     InvalidFunctionApplication {
         #[label("the left side was not a function value")]
         span: SourceSpan,
     },
 
+    #[error("Pyret expected the condition of this expression to be a Boolean")]
+    NonBooleanCondition {
+        value: Box<str>,
+        #[label("this evaluated to {value}")]
+        condition: SourceSpan,
+    },
+
+    #[error("Pyret expected the predicate of this test to return a Boolean")]
+    NonBooleanPredicate {
+        value: Box<str>,
+        #[label("this returned {value}")]
+        predicate: SourceSpan,
+    },
+
+    #[error("Pyret expected the operands of {operator} to be Booleans")]
+    NonBooleanOperand {
+        operator: Box<str>,
+        value: Box<str>,
+        #[label("this evaluated to {value}")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret found none of the branches of this {expression} expression evaluated to true")]
+    NoBranchesMatched {
+        expression: Box<str>,
+        #[label]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret found no cases branch matched the {variant} value")]
+    NoCasesBranchMatched {
+        variant: Box<str>,
+        #[label("this cases expression has no branch for {variant}")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret found a method without any arguments")]
+    MissingSelf {
+        #[label("methods must take at least one argument, for self")]
+        method: SourceSpan,
+    },
+
+    #[error("Pyret found the operators {left} and {right} mixed without parentheses")]
+    #[diagnostic(help(
+        "Pyret does not have operator precedence, so different operators have to be grouped with parentheses, like `(a {left} b) {right} c`"
+    ))]
+    MixedOperators {
+        left: Box<str>,
+        right: Box<str>,
+        #[label("this {left}")]
+        left_span: SourceSpan,
+        #[label("is mixed with this {right}")]
+        right_span: SourceSpan,
+    },
+
+    #[error("Pyret expected the left side of this lookup to be an object")]
+    NotAnObject {
+        value: Box<str>,
+        #[label("this evaluated to {value}")]
+        object: SourceSpan,
+    },
+
+    #[error("Pyret expected {ident} to be a type, but it is a value")]
+    NotAType {
+        ident: Box<str>,
+        #[label("this is not a type")]
+        span: Option<SourceSpan>,
+    },
+
+    #[error("Pyret expected {ident} to be a value, but it is a type")]
+    NotAValue {
+        ident: Box<str>,
+        #[label("this is not a value")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret expected the field {field} to be a ref field")]
+    NotARefField {
+        field: Box<str>,
+        #[label("only ref fields can be looked up with ! or updated")]
+        property: SourceSpan,
+    },
+
+    #[error("Pyret expected this expression to evaluate to a tuple")]
+    NotATuple {
+        value: Box<str>,
+        #[label("this evaluated to {value}")]
+        tuple: SourceSpan,
+    },
+
     #[error("Pyret thinks your program has an invalid number")]
     InvalidNumber {
         #[label("number literals in Pyret require at least one digit before the decimal point")]
         number: SourceSpan,
     },
 
+    #[error("Pyret expected the operands of {operator} to be {expected}")]
+    InvalidOperands {
+        operator: Box<str>,
+        expected: Box<str>,
+        left: Box<str>,
+        right: Box<str>,
+        #[label("the left side was {left}")]
+        left_span: Option<SourceSpan>,
+        #[label("the right side was {right}")]
+        right_span: Option<SourceSpan>,
+    },
+
+    #[error("Pyret found an update to the ref field {field} that does not satisfy its annotation")]
+    InvalidRefUpdate {
+        field: Box<str>,
+        value: Box<str>,
+        #[label("this evaluated to {value}")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret expects the index of a tuple lookup to be a non-negative integer")]
+    InvalidTupleIndex {
+        #[label]
+        index: SourceSpan,
+    },
+
     #[error("Pyret found an invalid string")]
     InvalidString {
         #[label]
@@ -108,12 +319,56 @@ pub enum PyretErrorKind {
         operator: SourceSpan,
     },
 
+    #[error(
+        "Pyret ran out of room for this application, as it nests more than {limit} calls deep"
+    )]
+    RecursionLimit {
+        limit: usize,
+        #[label("this application is too deep")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret expected the result of {function} to satisfy its annotation")]
+    ReturnTypeMismatch {
+        function: Box<str>,
+        value: Box<str>,
+        #[label("this returned {value}")]
+        span: SourceSpan,
+    },
+
     #[error("Pyret found a Roughnum overflow")]
     RoughNumberOverflow {
         #[label]
         number: SourceSpan,
     },
 
+    #[error("Pyret cannot look up the ref field {field} with a dot")]
+    RefFieldLookup {
+        field: Box<str>,
+        #[label("use ! to look up the value of a ref field")]
+        property: SourceSpan,
+    },
+
+    #[error(
+        "Pyret found the declaration of {ident} shadows a built-in declaration of the same name"
+    )]
+    ShadowsBuiltin {
+        ident: Box<str>,
+        #[label("this shadows the built-in {ident}")]
+        span: SourceSpan,
+    },
+
+    #[error(
+        "Pyret found the declaration of {ident} shadows a previous declaration of the same name"
+    )]
+    ShadowedDeclaration {
+        ident: Box<str>,
+        #[label("this shadows an earlier {ident}")]
+        span: SourceSpan,
+        #[label("it was previously declared here")]
+        previous: Option<SourceSpan>,
+    },
+
     #[error("Pyret thinks you're missing something before here")]
     SomethingBefore {
         #[label]
@@ -135,6 +390,86 @@ pub enum PyretErrorKind {
         span: SourceSpan,
     },
 
+    #[error("Pyret found the type {ident} is unbound")]
+    UnboundType {
+        ident: Box<str>,
+        #[label("it is used but not previously defined")]
+        span: Option<SourceSpan>,
+    },
+
+    #[error("Pyret found the identifier {ident} is used before it is initialized to a value")]
+    UninitializedIdentifier {
+        ident: Box<str>,
+        #[label("it is declared but has no value yet")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret found the identifier {ident} is used before it is defined")]
+    UsedBeforeDefinition {
+        ident: Box<str>,
+        #[label("it is used here")]
+        span: SourceSpan,
+        #[label("but only defined here")]
+        definition: SourceSpan,
+    },
+
+    #[error("Pyret found a test outside of a check:, where: or examples: block")]
+    TestOutsideCheck {
+        #[label("tests can only be written inside testing blocks")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret expected a tuple with {expected} elements, but found one with {found}")]
+    TupleArityMismatch {
+        expected: usize,
+        found: usize,
+        #[label("this tuple has {found} elements")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret found the index {index} is too large for a tuple with {length} elements")]
+    TupleIndexOutOfBounds {
+        index: usize,
+        length: usize,
+        #[label]
+        span: SourceSpan,
+    },
+
+    #[error(
+        "Pyret expected this expression to have the type {expected}, but it has the type {found}"
+    )]
+    TypeMismatch {
+        expected: Box<str>,
+        found: Box<str>,
+        #[label("this has the type {found}")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret expected {ident} to be given {expected} type parameters, but found {found}")]
+    TypeArityMismatch {
+        ident: Box<str>,
+        expected: usize,
+        found: usize,
+        #[label("this annotation gives {found}")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret expected this expression to be {expected}, but it has the type {found}")]
+    TypeKindMismatch {
+        expected: Box<str>,
+        found: Box<str>,
+        #[label("this has the type {found}")]
+        span: SourceSpan,
+    },
+
+    #[error("Pyret found a block that was never closed")]
+    #[diagnostic(help("add an end (or the matching closing symbol) where the block should stop"))]
+    UnclosedBlock {
+        opener: Box<str>,
+        #[label("this `{opener}` was never closed")]
+        span: SourceSpan,
+    },
+
     #[error("Pyret found an unexpected {}", found.name)]
     Unexpected {
         expected: Box<str>,
@@ -161,10 +496,11 @@ pub enum PyretErrorKind {
         position: SourceSpan,
     },
 
-    #[error("Pyret didn't expect your program to end as soon as it did")]
-    UnclosedParenthesis {
-        #[label]
-        position: SourceSpan,
+    #[error("Pyret does not support {feature} yet")]
+    Unsupported {
+        feature: Box<str>,
+        #[label("this is not supported yet")]
+        span: SourceSpan,
     },
 
     #[error("{0}")]
@@ -0,0 +1,124 @@
+use super::types::{Scheme, Type};
+
+/// The types of the values of the global trove, which are registered at
+/// runtime with predicates that cannot be checked statically.
+pub fn global() -> Vec<(&'static str, Scheme)> {
+    let number = || Type::named("Number");
+    let string = || Type::named("String");
+    let boolean = || Type::named("Boolean");
+
+    let arrow = |arguments: Vec<Type>, result: Type| Type::Arrow(arguments, Box::new(result));
+
+    let identity = Scheme {
+        parameters: vec![Box::from("a")],
+        variables: Vec::new(),
+        r#type: arrow(
+            vec![Type::Parameter(Box::from("a"))],
+            Type::Parameter(Box::from("a")),
+        ),
+    };
+
+    let mut values = vec![
+        ("display", identity.clone()),
+        ("print", identity),
+        (
+            "raise",
+            Scheme::monomorphic(arrow(vec![Type::Any], Type::Any)),
+        ),
+        ("nothing", Scheme::monomorphic(Type::named("Nothing"))),
+        ("PI", Scheme::monomorphic(number())),
+        ("E", Scheme::monomorphic(number())),
+        (
+            "not",
+            Scheme::monomorphic(arrow(vec![boolean()], boolean())),
+        ),
+        (
+            "is-equal",
+            Scheme::monomorphic(arrow(vec![number(), number()], boolean())),
+        ),
+        (
+            "num-to-string",
+            Scheme::monomorphic(arrow(vec![number()], string())),
+        ),
+        (
+            "string-append",
+            Scheme::monomorphic(arrow(vec![string(), string()], string())),
+        ),
+        (
+            "string-length",
+            Scheme::monomorphic(arrow(vec![string()], number())),
+        ),
+    ];
+
+    for name in ["is-nothing", "is-number", "is-boolean"] {
+        values.push((name, Scheme::monomorphic(arrow(vec![Type::Any], boolean()))));
+    }
+
+    for name in ["_plus", "_minus", "_times", "_divide"] {
+        values.push((
+            name,
+            Scheme::monomorphic(arrow(vec![Type::Any, Type::Any], Type::Any)),
+        ));
+    }
+
+    for name in ["string-equal", "string-contains"] {
+        values.push((
+            name,
+            Scheme::monomorphic(arrow(vec![string(), string()], boolean())),
+        ));
+    }
+
+    for name in [
+        "num-abs",
+        "num-sin",
+        "num-cos",
+        "num-tan",
+        "num-asin",
+        "num-acos",
+        "num-atan",
+        "num-truncate",
+        "num-sqrt",
+        "num-sqr",
+        "num-ceiling",
+        "num-floor",
+        "num-round",
+        "num-round-even",
+        "num-log",
+        "num-exp",
+        "num-to-roughnum",
+    ] {
+        values.push((name, Scheme::monomorphic(arrow(vec![number()], number()))));
+    }
+
+    for name in ["num-max", "num-min", "num-atan2", "num-modulo", "num-expn"] {
+        values.push((
+            name,
+            Scheme::monomorphic(arrow(vec![number(), number()], number())),
+        ));
+    }
+
+    for name in [
+        "num-is-integer",
+        "num-is-rational",
+        "num-is-positive",
+        "num-is-negative",
+        "num-is-non-positive",
+        "num-is-non-negative",
+    ] {
+        values.push((name, Scheme::monomorphic(arrow(vec![number()], boolean()))));
+    }
+
+    values
+}
+
+/// Resolves the name of a builtin type, where the refinements of `Number`
+/// like `NumInteger` are checked as `Number`.
+pub fn named_type(name: &str) -> Option<Type> {
+    Some(match name {
+        "Any" => Type::Any,
+        "Number" | "Exactnum" | "Roughnum" | "NumInteger" | "NumRational" | "NumPositive"
+        | "NumNegative" | "NumNonPositive" | "NumNonNegative" => Type::named("Number"),
+        "String" | "Boolean" | "Nothing" => Type::named(name),
+        _ => return None,
+    })
+}
@@ -0,0 +1,1120 @@
+mod builtins;
+pub mod types;
+
+use std::{collections::HashMap, ops::Range};
+
+use pyret_error::PyretErrorKind;
+use pyret_lexer::{
+    ast::{self, BinaryOperation, LetDeclarationKind},
+    Token,
+};
+
+use self::types::{Scheme, Type};
+
+/// What a name in an annotation refers to.
+#[derive(Clone, Copy)]
+enum TypeName {
+    /// A type parameter of the function or declaration being checked.
+    Parameter,
+    /// A `data` declaration with this many type parameters.
+    Data(usize),
+}
+
+/// A variant of a `data` declaration, with the types of its fields in terms
+/// of the type parameters of the declaration.
+struct Variant {
+    name: Box<str>,
+    fields: Option<Vec<(Box<str>, Type)>>,
+}
+
+/// A `data` declaration, whose fields are looked up by dots and `cases`.
+struct DataType {
+    parameters: Vec<Box<str>>,
+    variants: Vec<Variant>,
+    /// The names of the members of the `with:` and `sharing:` sections,
+    /// which are not checked.
+    members: Vec<Box<str>>,
+}
+
+struct Binding {
+    scheme: Scheme,
+    is_variable: bool,
+}
+
+/// Infers the types of a program before it is interpreted, collecting the
+/// expressions whose types do not match.
+///
+/// Bindings and parameters without annotations are inferred, and values of
+/// type `Any` are never reported.
+pub struct TypeChecker {
+    values: Vec<HashMap<Box<str>, Binding>>,
+    types: Vec<HashMap<Box<str>, TypeName>>,
+    data: HashMap<Box<str>, DataType>,
+    /// The types that the variables have been inferred to be so far.
+    substitution: Vec<Option<Type>>,
+    errors: Vec<PyretErrorKind>,
+}
+
+impl Default for TypeChecker {
+    fn default() -> Self {
+        let globals = builtins::global()
+            .into_iter()
+            .map(|(name, scheme)| {
+                (
+                    Box::from(name),
+                    Binding {
+                        scheme,
+                        is_variable: false,
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            values: vec![globals],
+            types: vec![HashMap::new()],
+            data: HashMap::new(),
+            substitution: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl TypeChecker {
+    /// Checks a program, resulting in the type errors it contains.
+    #[must_use]
+    pub fn check(mut self, program: &[ast::Statement]) -> Vec<PyretErrorKind> {
+        self.check_block(program);
+
+        self.errors
+    }
+
+    fn fresh(&mut self) -> Type {
+        self.substitution.push(None);
+
+        Type::Variable(self.substitution.len() - 1)
+    }
+
+    /// Follows the variables that have been inferred at the top of a type.
+    fn resolve(&self, r#type: &Type) -> Type {
+        match r#type {
+            Type::Variable(variable) => match &self.substitution[*variable] {
+                Some(inferred) => self.resolve(inferred),
+                None => r#type.clone(),
+            },
+            _ => r#type.clone(),
+        }
+    }
+
+    /// Replaces every variable that has been inferred within a type.
+    fn zonk(&self, r#type: &Type) -> Type {
+        r#type.substitute(&|r#type| match r#type {
+            Type::Variable(variable) => self.substitution[*variable]
+                .as_ref()
+                .map(|inferred| self.zonk(inferred)),
+            _ => None,
+        })
+    }
+
+    /// Makes two types equal by inferring their variables, where `expected`
+    /// may be a record with fewer fields than `found`.
+    fn unify(&mut self, expected: &Type, found: &Type) -> bool {
+        let (expected, found) = (self.resolve(expected), self.resolve(found));
+
+        match (&expected, &found) {
+            (Type::Any, _) | (_, Type::Any) => true,
+            (Type::Variable(left), Type::Variable(right)) if left == right => true,
+            (Type::Variable(variable), other) | (other, Type::Variable(variable)) => {
+                let mut variables = Vec::new();
+
+                self.zonk(other).variables(&mut variables);
+
+                if variables.contains(variable) {
+                    return false;
+                }
+
+                self.substitution[*variable] = Some(other.clone());
+
+                true
+            }
+            (Type::Named(left, left_parameters), Type::Named(right, right_parameters)) => {
+                left == right && self.unify_all(left_parameters, right_parameters)
+            }
+            (Type::Parameter(left), Type::Parameter(right)) => left == right,
+            (
+                Type::Arrow(left_arguments, left_result),
+                Type::Arrow(right_arguments, right_result),
+            ) => {
+                self.unify_all(left_arguments, right_arguments)
+                    && self.unify(left_result, right_result)
+            }
+            (Type::Tuple(left_elements), Type::Tuple(right_elements)) => {
+                self.unify_all(left_elements, right_elements)
+            }
+            (Type::Record(left_fields), Type::Record(right_fields)) => {
+                left_fields.iter().all(|(name, left)| {
+                    right_fields
+                        .get(name)
+                        .is_some_and(|right| self.unify(left, right))
+                })
+            }
+            _ => false,
+        }
+    }
+
+    fn unify_all(&mut self, expected: &[Type], found: &[Type]) -> bool {
+        expected.len() == found.len()
+            && expected
+                .iter()
+                .zip(found)
+                .all(|(expected, found)| self.unify(expected, found))
+    }
+
+    /// Reports a mismatch at the span if the found type cannot be made equal
+    /// to the expected one.
+    fn expect(&mut self, expected: &Type, found: &Type, span: Range<usize>) {
+        if !self.unify(expected, found) {
+            self.errors.push(PyretErrorKind::TypeMismatch {
+                expected: self.zonk(expected).to_string().into_boxed_str(),
+                found: self.zonk(found).to_string().into_boxed_str(),
+                span: span.into(),
+            });
+        }
+    }
+
+    fn check_expression(&mut self, expr: &ast::ExpressionStatement, expected: &Type) {
+        let found = self.infer(expr);
+
+        self.expect(expected, &found, expr.span());
+    }
+
+    fn mismatched_kind(&mut self, expected: &str, found: &Type, span: Range<usize>) {
+        self.errors.push(PyretErrorKind::TypeKindMismatch {
+            expected: Box::from(expected),
+            found: self.zonk(found).to_string().into_boxed_str(),
+            span: span.into(),
+        });
+    }
+
+    fn bind(&mut self, name: &str, scheme: Scheme, is_variable: bool) {
+        if let Some(scope) = self.values.last_mut() {
+            scope.insert(
+                Box::from(name),
+                Binding {
+                    scheme,
+                    is_variable,
+                },
+            );
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Option<&Binding> {
+        self.values.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    /// Creates a type from a scheme, with fresh variables for its type
+    /// parameters and the variables it is generic over.
+    fn instantiate(&mut self, scheme: &Scheme) -> Type {
+        let parameters = scheme
+            .parameters
+            .iter()
+            .map(|parameter| (parameter.clone(), self.fresh()))
+            .collect::<HashMap<_, _>>();
+
+        let variables = scheme
+            .variables
+            .iter()
+            .map(|variable| (*variable, self.fresh()))
+            .collect::<HashMap<_, _>>();
+
+        self.zonk(&scheme.r#type)
+            .substitute(&|r#type| match r#type {
+                Type::Parameter(name) => parameters.get(name).cloned(),
+                Type::Variable(variable) => variables.get(variable).cloned(),
+                _ => None,
+            })
+    }
+
+    /// Makes the type of the binding `name` generic over the variables that
+    /// no other binding in scope depends on.
+    fn generalize(&self, name: &str, parameters: Vec<Box<str>>, r#type: &Type) -> Scheme {
+        let r#type = self.zonk(r#type);
+        let innermost = self.values.len() - 1;
+
+        let mut bound = Vec::new();
+
+        for (depth, scope) in self.values.iter().enumerate() {
+            for (key, binding) in scope {
+                if depth == innermost && &**key == name {
+                    continue;
+                }
+
+                let mut variables = Vec::new();
+
+                self.zonk(&binding.scheme.r#type).variables(&mut variables);
+
+                bound.extend(
+                    variables
+                        .into_iter()
+                        .filter(|variable| !binding.scheme.variables.contains(variable)),
+                );
+            }
+        }
+
+        let mut variables = Vec::new();
+
+        r#type.variables(&mut variables);
+        variables.retain(|variable| !bound.contains(variable));
+
+        Scheme {
+            parameters,
+            variables,
+            r#type,
+        }
+    }
+
+    fn push_type_parameters(&mut self, parameters: &[ast::IdentifierExpression]) {
+        self.types.push(
+            parameters
+                .iter()
+                .map(|ident| (ident.name.clone(), TypeName::Parameter))
+                .collect(),
+        );
+    }
+
+    fn annotation_type(&mut self, annotation: &ast::AnnotationType) -> Type {
+        match annotation {
+            ast::AnnotationType::NameAnnotation {
+                name: ast::IdentifierAnnotation::Name(ident),
+                parameters,
+                ..
+            } => {
+                let type_name = self
+                    .types
+                    .iter()
+                    .rev()
+                    .find_map(|scope| scope.get(&ident.name).copied());
+
+                match type_name {
+                    Some(TypeName::Parameter) => Type::Parameter(ident.name.clone()),
+                    Some(TypeName::Data(arity)) => {
+                        let parameters = if parameters.len() == arity {
+                            parameters
+                                .iter()
+                                .map(|parameter| self.annotation_type(parameter))
+                                .collect()
+                        } else {
+                            vec![Type::Any; arity]
+                        };
+
+                        Type::Named(ident.name.clone(), parameters)
+                    }
+                    None => builtins::named_type(&ident.name).unwrap_or(Type::Any),
+                }
+            }
+            ast::AnnotationType::NameAnnotation { .. } => Type::Any,
+            ast::AnnotationType::ArrowAnnotation {
+                arguments,
+                return_annotation,
+                ..
+            } => Type::Arrow(
+                arguments
+                    .iter()
+                    .map(|argument| self.annotation_type(argument))
+                    .collect(),
+                Box::new(self.annotation_type(return_annotation)),
+            ),
+            ast::AnnotationType::TupleAnnotation(elements) => Type::Tuple(
+                elements
+                    .iter()
+                    .map(|element| self.annotation_type(element))
+                    .collect(),
+            ),
+            ast::AnnotationType::RecordAnnotation(fields) => Type::Record(
+                fields
+                    .iter()
+                    .map(|(name, field)| (name.clone(), self.annotation_type(field)))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// The type of an optional annotation, which is inferred if it is
+    /// missing.
+    fn annotation_or_fresh(&mut self, annotation: Option<&ast::TypeAnnotation>) -> Type {
+        match annotation {
+            Some(annotation) => self.annotation_type(&annotation.value),
+            None => self.fresh(),
+        }
+    }
+
+    /// The type of a function from the annotations of its parameters and
+    /// result, where the receiver of a method is not checked.
+    fn signature(&mut self, function: &ast::Function, is_method: bool) -> Type {
+        self.push_type_parameters(&function.generics);
+
+        let params = function
+            .params
+            .iter()
+            .enumerate()
+            .map(|(index, param)| match &param.annotation {
+                None if is_method && index == 0 => Type::Any,
+                annotation => self.annotation_or_fresh(annotation.as_ref()),
+            })
+            .collect();
+
+        let result = self.annotation_or_fresh(function.return_annotation.as_ref());
+
+        self.types.pop();
+
+        Type::Arrow(params, Box::new(result))
+    }
+
+    /// Checks the body of a function against its signature.
+    fn check_function(&mut self, function: &ast::Function, signature: &Type) {
+        let Type::Arrow(params, result) = signature else {
+            return;
+        };
+
+        self.push_type_parameters(&function.generics);
+        self.values.push(HashMap::new());
+
+        for (param, r#type) in function.params.iter().zip(params) {
+            self.bind(
+                &param.ident.name,
+                Scheme::monomorphic(r#type.clone()),
+                false,
+            );
+        }
+
+        self.check_body(&function.body, result);
+
+        self.values.pop();
+        self.types.pop();
+    }
+
+    fn check_body(&mut self, body: &[ast::Statement], expected: &Type) {
+        let found = self.check_block(body);
+
+        if let Some(last) = body.last() {
+            self.expect(expected, &found, last.span());
+        }
+    }
+
+    /// Checks the statements of a block in a new scope, resulting in the type
+    /// of the last one.
+    fn check_block(&mut self, block: &[ast::Statement]) -> Type {
+        self.values.push(HashMap::new());
+        self.types.push(HashMap::new());
+
+        self.declare(block);
+
+        let mut r#type = Type::named("Nothing");
+
+        for stmt in block {
+            r#type = self.check_statement(stmt);
+        }
+
+        self.types.pop();
+        self.values.pop();
+
+        r#type
+    }
+
+    /// Declares the `data` types and functions of a block before it is
+    /// checked, so that they can refer to each other.
+    fn declare(&mut self, block: &[ast::Statement]) {
+        for stmt in block {
+            if let ast::Statement::Declaration(ast::DeclarationStatement::Data(data)) = stmt {
+                if let Some(scope) = self.types.last_mut() {
+                    scope.insert(data.ident.name.clone(), TypeName::Data(data.generics.len()));
+                }
+            }
+        }
+
+        for stmt in block {
+            match stmt {
+                ast::Statement::Declaration(ast::DeclarationStatement::Data(data)) => {
+                    self.declare_data(data);
+                }
+                ast::Statement::Declaration(ast::DeclarationStatement::Function(declaration)) => {
+                    let signature = self.signature(&declaration.function, false);
+
+                    let parameters = declaration
+                        .function
+                        .generics
+                        .iter()
+                        .map(|ident| ident.name.clone())
+                        .collect();
+
+                    self.bind(
+                        &declaration.ident.name,
+                        Scheme {
+                            parameters,
+                            variables: Vec::new(),
+                            r#type: signature,
+                        },
+                        false,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Declares the constructors and predicates of the variants of a `data`
+    /// declaration.
+    fn declare_data(&mut self, data: &ast::DataDeclaration) {
+        let parameters = data
+            .generics
+            .iter()
+            .map(|ident| ident.name.clone())
+            .collect::<Vec<_>>();
+
+        let r#type = Type::Named(
+            data.ident.name.clone(),
+            parameters.iter().cloned().map(Type::Parameter).collect(),
+        );
+
+        let predicate = Type::Arrow(vec![Type::Any], Box::new(Type::named("Boolean")));
+
+        self.bind(
+            &format!("is-{}", data.ident.name),
+            Scheme::monomorphic(predicate.clone()),
+            false,
+        );
+
+        self.push_type_parameters(&data.generics);
+
+        let mut variants = Vec::new();
+
+        for variant in &data.variants {
+            let fields = variant.fields.as_ref().map(|fields| {
+                fields
+                    .iter()
+                    .map(|field| {
+                        let r#type = field.annotation.as_ref().map_or(Type::Any, |annotation| {
+                            self.annotation_type(&annotation.value)
+                        });
+
+                        (field.ident.name.clone(), r#type)
+                    })
+                    .collect::<Vec<_>>()
+            });
+
+            let constructor = match &fields {
+                Some(fields) => Type::Arrow(
+                    fields.iter().map(|(_, r#type)| r#type.clone()).collect(),
+                    Box::new(r#type.clone()),
+                ),
+                None => r#type.clone(),
+            };
+
+            self.bind(
+                &variant.ident.name,
+                Scheme {
+                    parameters: parameters.clone(),
+                    variables: Vec::new(),
+                    r#type: constructor,
+                },
+                false,
+            );
+
+            self.bind(
+                &format!("is-{}", variant.ident.name),
+                Scheme::monomorphic(predicate.clone()),
+                false,
+            );
+
+            variants.push(Variant {
+                name: variant.ident.name.clone(),
+                fields,
+            });
+        }
+
+        self.types.pop();
+
+        let members = data
+            .variants
+            .iter()
+            .flat_map(|variant| &variant.members)
+            .chain(&data.shared)
+            .map(|member| member.key.name.clone())
+            .collect();
+
+        self.data.insert(
+            data.ident.name.clone(),
+            DataType {
+                parameters,
+                variants,
+                members,
+            },
+        );
+    }
+
+    fn check_statement(&mut self, stmt: &ast::Statement) -> Type {
+        match stmt {
+            ast::Statement::Declaration(decl) => self.check_declaration(decl),
+            ast::Statement::Import(import) => match &import.value {
+                ast::Import::As { name, .. } => {
+                    self.bind(&name.name, Scheme::monomorphic(Type::Any), false);
+                }
+                ast::Import::From { names, .. } => {
+                    for name in names {
+                        self.bind(&name.name, Scheme::monomorphic(Type::Any), false);
+                    }
+                }
+            },
+            ast::Statement::Expression(expr) => return self.infer(expr),
+            ast::Statement::Symbol(..) | ast::Statement::Provide(..) => {}
+        }
+
+        Type::named("Nothing")
+    }
+
+    fn check_declaration(&mut self, decl: &ast::DeclarationStatement) {
+        match decl {
+            ast::DeclarationStatement::Check(check) => {
+                self.check_block(&check.body);
+            }
+            ast::DeclarationStatement::Data(data) => {
+                for member in data
+                    .variants
+                    .iter()
+                    .flat_map(|variant| &variant.members)
+                    .chain(&data.shared)
+                {
+                    self.infer(&member.value);
+                }
+            }
+            ast::DeclarationStatement::Function(declaration) => {
+                let name = &declaration.ident.name;
+
+                let Some(scheme) = self.lookup(name).map(|binding| binding.scheme.clone()) else {
+                    return;
+                };
+
+                self.check_function(&declaration.function, &scheme.r#type);
+
+                let scheme = self.generalize(name, scheme.parameters, &scheme.r#type);
+
+                self.bind(name, scheme, false);
+
+                if let Some(where_block) = &declaration.where_block {
+                    self.check_block(where_block);
+                }
+            }
+            ast::DeclarationStatement::Let(declaration) => self.check_let(declaration),
+        }
+    }
+
+    fn check_let(&mut self, declaration: &ast::LetDeclaration) {
+        match &declaration.binding {
+            ast::LetBinding::Name(ident) => {
+                let r#type = self.annotation_or_fresh(declaration.annotation.as_ref());
+
+                if declaration.kind == LetDeclarationKind::RecursiveLet {
+                    self.bind(&ident.name, Scheme::monomorphic(r#type.clone()), false);
+                }
+
+                if let Some(init) = &declaration.init {
+                    self.check_expression(init, &r#type);
+                }
+
+                let is_variable = declaration.kind == LetDeclarationKind::Variable;
+
+                // Only functions are generic, like in `id = lam(x): x end`,
+                // as the types of other values are fixed once inferred.
+                let scheme = match &declaration.init {
+                    Some(
+                        ast::ExpressionStatement::Lambda(..)
+                        | ast::ExpressionStatement::CurlyLambda(..),
+                    ) if !is_variable => self.generalize(&ident.name, Vec::new(), &r#type),
+                    _ => Scheme::monomorphic(r#type),
+                };
+
+                self.bind(&ident.name, scheme, is_variable);
+            }
+            ast::LetBinding::Tuple(binding) => {
+                if let Some(init) = &declaration.init {
+                    let r#type = self.infer(init);
+
+                    self.bind_tuple(binding, &r#type, init.span());
+                }
+            }
+        }
+    }
+
+    fn bind_tuple(&mut self, binding: &ast::TupleBinding, r#type: &Type, span: Range<usize>) {
+        let elements = match self.resolve(r#type) {
+            Type::Tuple(elements) if elements.len() == binding.bindings.len() => elements,
+            Type::Tuple(elements) => {
+                self.errors.push(PyretErrorKind::TupleArityMismatch {
+                    expected: binding.bindings.len(),
+                    found: elements.len(),
+                    span: span.clone().into(),
+                });
+
+                vec![Type::Any; binding.bindings.len()]
+            }
+            Type::Variable(..) => {
+                let elements = binding
+                    .bindings
+                    .iter()
+                    .map(|_| self.fresh())
+                    .collect::<Vec<_>>();
+
+                self.unify(r#type, &Type::Tuple(elements.clone()));
+
+                elements
+            }
+            Type::Any => vec![Type::Any; binding.bindings.len()],
+            found => {
+                self.mismatched_kind("a tuple", &found, span.clone());
+
+                vec![Type::Any; binding.bindings.len()]
+            }
+        };
+
+        for (binding, r#type) in binding.bindings.iter().zip(elements) {
+            match binding {
+                ast::LetBinding::Name(ident) => {
+                    self.bind(&ident.name, Scheme::monomorphic(r#type), false);
+                }
+                ast::LetBinding::Tuple(binding) => self.bind_tuple(binding, &r#type, span.clone()),
+            }
+        }
+    }
+
+    fn infer(&mut self, expr: &ast::ExpressionStatement) -> Type {
+        match expr {
+            ast::ExpressionStatement::Application(app) => self.infer_application(app),
+            ast::ExpressionStatement::Assign(assign) => {
+                let found = self.infer(&assign.value);
+
+                let variable = self
+                    .lookup(&assign.ident.name)
+                    .filter(|binding| binding.is_variable)
+                    .map(|binding| binding.scheme.r#type.clone());
+
+                if let Some(expected) = variable {
+                    self.expect(&expected, &found, assign.value.span());
+                }
+
+                Type::named("Nothing")
+            }
+            ast::ExpressionStatement::Block(block) => self.check_block(&block.body),
+            ast::ExpressionStatement::Lambda(ast::LambdaExpression { function, .. })
+            | ast::ExpressionStatement::CurlyLambda(ast::CurlyLambdaExpression {
+                function, ..
+            }) => {
+                let signature = self.signature(function, false);
+
+                self.check_function(function, &signature);
+
+                signature
+            }
+            // Methods are only applied once they are bound to an object.
+            ast::ExpressionStatement::Method(method) => {
+                let signature = self.signature(&method.function, true);
+
+                self.check_function(&method.function, &signature);
+
+                Type::Any
+            }
+            ast::ExpressionStatement::If(if_expr) => {
+                self.infer_branches(&if_expr.branches, if_expr.else_body.as_deref())
+            }
+            ast::ExpressionStatement::Ask(ask) => {
+                self.infer_branches(&ask.branches, ask.otherwise.as_deref())
+            }
+            ast::ExpressionStatement::Cases(cases) => self.infer_cases(cases),
+            ast::ExpressionStatement::Literal(literal) => Type::named(match literal {
+                ast::LiteralExpression::String(..) => "String",
+                ast::LiteralExpression::Number(..) => "Number",
+                ast::LiteralExpression::Boolean(..) => "Boolean",
+            }),
+            ast::ExpressionStatement::Object(object) => Type::Record(
+                object
+                    .fields
+                    .iter()
+                    .map(|field| (field.key.name.clone(), self.infer(&field.value)))
+                    .collect(),
+            ),
+            ast::ExpressionStatement::Tuple(tuple) => Type::Tuple(
+                tuple
+                    .elements
+                    .iter()
+                    .map(|element| self.infer(element))
+                    .collect(),
+            ),
+            ast::ExpressionStatement::TupleGet(tuple_get) => {
+                let tuple = self.infer(&tuple_get.tuple);
+
+                match self.resolve(&tuple) {
+                    Type::Tuple(elements) => {
+                        if let Some(element) = elements.get(tuple_get.index) {
+                            element.clone()
+                        } else {
+                            self.errors.push(PyretErrorKind::TupleIndexOutOfBounds {
+                                index: tuple_get.index,
+                                length: elements.len(),
+                                span: tuple_get.span().into(),
+                            });
+
+                            Type::Any
+                        }
+                    }
+                    Type::Any | Type::Variable(..) => Type::Any,
+                    found => {
+                        self.mismatched_kind("a tuple", &found, tuple_get.tuple.span());
+
+                        Type::Any
+                    }
+                }
+            }
+            ast::ExpressionStatement::BinaryOperator(binary_op) => self.infer_binary(binary_op),
+            ast::ExpressionStatement::Identifier(ident) => match self.lookup(&ident.name) {
+                Some(binding) => {
+                    let scheme = binding.scheme.clone();
+
+                    self.instantiate(&scheme)
+                }
+                None => Type::Any,
+            },
+            ast::ExpressionStatement::Dot(dot) => {
+                let object = self.infer(&dot.object);
+
+                self.field_type(&object, &dot.property, dot.object.span())
+            }
+            ast::ExpressionStatement::GetBang(get_bang) => {
+                let object = self.infer(&get_bang.object);
+
+                match self.resolve(&object) {
+                    Type::Record(..) => {
+                        self.field_type(&object, &get_bang.property, get_bang.object.span())
+                    }
+                    _ => Type::Any,
+                }
+            }
+            ast::ExpressionStatement::Update(update) => {
+                let object = self.infer(&update.object);
+
+                for field in &update.fields {
+                    let found = self.infer(&field.value);
+
+                    if let Type::Record(fields) = self.resolve(&object) {
+                        if let Some(expected) = fields.get(&field.key.name) {
+                            self.expect(expected, &found, field.value.span());
+                        }
+                    }
+                }
+
+                object
+            }
+            ast::ExpressionStatement::Parenthesis(parenthesis) => self.infer(&parenthesis.expr),
+        }
+    }
+
+    fn infer_application(&mut self, app: &ast::ApplicationExpression) -> Type {
+        let callee = self.infer(&app.callee);
+
+        match self.resolve(&callee) {
+            Type::Arrow(params, result) if params.len() == app.args.len() => {
+                for (arg, param) in app.args.iter().zip(&params) {
+                    self.check_expression(arg, param);
+                }
+
+                *result
+            }
+            Type::Arrow(params, result) => {
+                let function = match &*app.callee {
+                    ast::ExpressionStatement::Identifier(ident) => ident.name.clone(),
+                    _ => Box::from("this function"),
+                };
+
+                self.errors.push(PyretErrorKind::ArityMismatch {
+                    function,
+                    expected: params.len(),
+                    found: app.args.len(),
+                    span: app.span().into(),
+                });
+
+                for arg in &app.args {
+                    self.infer(arg);
+                }
+
+                *result
+            }
+            Type::Variable(..) => {
+                let params = app.args.iter().map(|arg| self.infer(arg)).collect();
+                let result = self.fresh();
+
+                self.unify(&callee, &Type::Arrow(params, Box::new(result.clone())));
+
+                result
+            }
+            found => {
+                if found != Type::Any {
+                    self.mismatched_kind("a function", &found, app.callee.span());
+                }
+
+                for arg in &app.args {
+                    self.infer(arg);
+                }
+
+                Type::Any
+            }
+        }
+    }
+
+    /// Looks up the type of a field of a record or of a `data` type, where
+    /// the span points at the object.
+    fn field_type(
+        &mut self,
+        object: &Type,
+        property: &ast::IdentifierExpression,
+        span: Range<usize>,
+    ) -> Type {
+        let field = match self.resolve(object) {
+            Type::Record(fields) => fields.get(&property.name).cloned(),
+            Type::Named(name, arguments) if self.data.contains_key(&name) => {
+                let data = &self.data[&name];
+
+                if data.members.contains(&property.name) {
+                    return Type::Any;
+                }
+
+                data.variants
+                    .iter()
+                    .filter_map(|variant| variant.fields.as_ref())
+                    .flatten()
+                    .find(|(field, _)| *field == property.name)
+                    .map(|(_, r#type)| substitute_parameters(r#type, &data.parameters, &arguments))
+            }
+            Type::Any | Type::Variable(..) => return Type::Any,
+            found => {
+                self.mismatched_kind("an object", &found, span);
+
+                return Type::Any;
+            }
+        };
+
+        field.unwrap_or_else(|| {
+            self.errors.push(PyretErrorKind::FieldNotFound {
+                field: property.name.clone(),
+                property: property.span().into(),
+            });
+
+            Type::Any
+        })
+    }
+
+    /// Checks the branches of an `if` or `ask` expression, whose conditions
+    /// are Booleans and whose bodies have the same type.
+    fn infer_branches(
+        &mut self,
+        branches: &[ast::ConditionalBranch],
+        else_body: Option<&[ast::Statement]>,
+    ) -> Type {
+        let boolean = Type::named("Boolean");
+        let result = self.fresh();
+
+        for branch in branches {
+            self.check_expression(&branch.condition, &boolean);
+            self.check_body(&branch.body, &result);
+        }
+
+        if let Some(else_body) = else_body {
+            self.check_body(else_body, &result);
+        }
+
+        result
+    }
+
+    fn infer_cases(&mut self, cases: &ast::CasesExpression) -> Type {
+        let annotated = self.annotation_type(&cases.annotation.value);
+
+        self.check_expression(&cases.value, &annotated);
+
+        let result = self.fresh();
+
+        for branch in &cases.branches {
+            let fields = match self.resolve(&annotated) {
+                Type::Named(name, arguments) => self.data.get(&name).and_then(|data| {
+                    let variant = data
+                        .variants
+                        .iter()
+                        .find(|variant| variant.name == branch.ident.name)?;
+
+                    Some(
+                        variant
+                            .fields
+                            .iter()
+                            .flatten()
+                            .map(|(_, r#type)| {
+                                substitute_parameters(r#type, &data.parameters, &arguments)
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                }),
+                _ => None,
+            };
+
+            self.values.push(HashMap::new());
+
+            if let Some(bindings) = &branch.bindings {
+                let fields = match fields {
+                    Some(fields) if fields.len() == bindings.len() => fields,
+                    Some(fields) => {
+                        self.errors.push(PyretErrorKind::CasesArityMismatch {
+                            variant: branch.ident.name.clone(),
+                            expected: fields.len(),
+                            found: bindings.len(),
+                            span: branch.ident.span().into(),
+                        });
+
+                        vec![Type::Any; bindings.len()]
+                    }
+                    None => vec![Type::Any; bindings.len()],
+                };
+
+                for (binding, r#type) in bindings.iter().zip(fields) {
+                    self.bind(&binding.ident.name, Scheme::monomorphic(r#type), false);
+                }
+            }
+
+            self.check_body(&branch.body, &result);
+
+            self.values.pop();
+        }
+
+        if let Some(else_body) = &cases.else_body {
+            self.check_body(else_body, &result);
+        }
+
+        result
+    }
+
+    fn infer_binary(&mut self, binary_op: &ast::BinaryOperatorExpression) -> Type {
+        let number = Type::named("Number");
+        let boolean = Type::named("Boolean");
+
+        let left = self.infer(&binary_op.left);
+        let right = binary_op.right.as_deref();
+
+        match binary_op.operator {
+            BinaryOperation::Plus
+            | BinaryOperation::LessThan
+            | BinaryOperation::LessThanOrEqual
+            | BinaryOperation::GreaterThan
+            | BinaryOperation::GreaterThanOrEqual => {
+                // Both operands are Numbers or both are Strings.
+                let operand = match self.resolve(&left) {
+                    Type::Named(name, _) if &*name == "Number" || &*name == "String" => left,
+                    Type::Any | Type::Variable(..) => left,
+                    found => {
+                        self.mismatched_kind("a Number or a String", &found, binary_op.left.span());
+
+                        Type::Any
+                    }
+                };
+
+                if let Some(right) = right {
+                    self.check_expression(right, &operand);
+                }
+
+                if binary_op.operator == BinaryOperation::Plus {
+                    operand
+                } else {
+                    boolean
+                }
+            }
+            BinaryOperation::Minus | BinaryOperation::Times | BinaryOperation::Divide => {
+                self.expect(&number, &left, binary_op.left.span());
+
+                if let Some(right) = right {
+                    self.check_expression(right, &number);
+                }
+
+                number
+            }
+            BinaryOperation::And | BinaryOperation::Or => {
+                self.expect(&boolean, &left, binary_op.left.span());
+
+                if let Some(right) = right {
+                    self.check_expression(right, &boolean);
+                }
+
+                boolean
+            }
+            BinaryOperation::Equal | BinaryOperation::NotEqual => {
+                if let Some(right) = right {
+                    self.infer(right);
+                }
+
+                boolean
+            }
+            BinaryOperation::Is
+            | BinaryOperation::IsNot
+            | BinaryOperation::IsEqual
+            | BinaryOperation::IsEqualNow
+            | BinaryOperation::IsIdentical
+            | BinaryOperation::IsRoughly => {
+                if let Some(right) = right {
+                    self.check_expression(right, &left);
+                }
+
+                Type::named("Nothing")
+            }
+            BinaryOperation::IsRefinement | BinaryOperation::IsNotRefinement => {
+                let right = right.map_or(Type::Any, |right| self.infer(right));
+
+                if let Some(refinement) = &binary_op.refinement {
+                    let predicate = Type::Arrow(vec![left, right], Box::new(boolean));
+
+                    self.check_expression(refinement, &predicate);
+                }
+
+                Type::named("Nothing")
+            }
+            BinaryOperation::Satisfies | BinaryOperation::Violates => {
+                if let Some(right) = right {
+                    self.check_expression(right, &Type::Arrow(vec![left], Box::new(boolean)));
+                }
+
+                Type::named("Nothing")
+            }
+            BinaryOperation::Raises | BinaryOperation::RaisesOtherThan => {
+                if let Some(right) = right {
+                    self.check_expression(right, &Type::named("String"));
+                }
+
+                Type::named("Nothing")
+            }
+            BinaryOperation::RaisesSatisfies | BinaryOperation::RaisesViolates => {
+                if let Some(right) = right {
+                    let predicate = Type::Arrow(vec![Type::Any], Box::new(boolean));
+
+                    self.check_expression(right, &predicate);
+                }
+
+                Type::named("Nothing")
+            }
+            BinaryOperation::DoesNotRaise => Type::named("Nothing"),
+        }
+    }
+}
+
+/// Replaces the type parameters of a `data` declaration in the type of one
+/// of its fields with the parameters of an annotation like `Box<Number>`.
+fn substitute_parameters(r#type: &Type, parameters: &[Box<str>], arguments: &[Type]) -> Type {
+    r#type.substitute(&|r#type| match r#type {
+        Type::Parameter(name) => parameters
+            .iter()
+            .position(|parameter| parameter == name)
+            .and_then(|index| arguments.get(index))
+            .cloned(),
+        _ => None,
+    })
+}
@@ -0,0 +1,164 @@
+use std::{collections::BTreeMap, fmt};
+
+/// The static type of an expression.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Type {
+    /// Satisfied by every value, which turns off checking, like for the
+    /// values of unannotated fields.
+    Any,
+    /// A builtin type like `Number`, or a `data` type applied to its type
+    /// parameters, like `Pair<Number, String>`.
+    Named(Box<str>, Vec<Type>),
+    /// A type parameter of a generic function or `data` declaration, which
+    /// is only equal to itself while its body is checked.
+    Parameter(Box<str>),
+    /// A type that is still being inferred.
+    Variable(usize),
+    Arrow(Vec<Type>, Box<Type>),
+    Tuple(Vec<Type>),
+    Record(BTreeMap<Box<str>, Type>),
+}
+
+impl Type {
+    #[must_use]
+    pub fn named(name: &str) -> Self {
+        Self::Named(Box::from(name), Vec::new())
+    }
+
+    /// Replaces the type parameters and variables that `substitution` maps
+    /// to other types.
+    #[must_use]
+    pub fn substitute(&self, substitution: &impl Fn(&Self) -> Option<Self>) -> Self {
+        if let Some(r#type) = substitution(self) {
+            return r#type;
+        }
+
+        let substitute_all = |types: &[Self]| {
+            types
+                .iter()
+                .map(|r#type| r#type.substitute(substitution))
+                .collect()
+        };
+
+        match self {
+            Self::Any | Self::Parameter(..) | Self::Variable(..) => self.clone(),
+            Self::Named(name, parameters) => Self::Named(name.clone(), substitute_all(parameters)),
+            Self::Arrow(arguments, result) => Self::Arrow(
+                substitute_all(arguments),
+                Box::new(result.substitute(substitution)),
+            ),
+            Self::Tuple(elements) => Self::Tuple(substitute_all(elements)),
+            Self::Record(fields) => Self::Record(
+                fields
+                    .iter()
+                    .map(|(name, r#type)| (name.clone(), r#type.substitute(substitution)))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Collects the variables that occur in the type.
+    pub fn variables(&self, variables: &mut Vec<usize>) {
+        match self {
+            Self::Variable(variable) => {
+                if !variables.contains(variable) {
+                    variables.push(*variable);
+                }
+            }
+            Self::Any | Self::Parameter(..) => {}
+            Self::Named(_, types) | Self::Tuple(types) => {
+                for r#type in types {
+                    r#type.variables(variables);
+                }
+            }
+            Self::Arrow(arguments, result) => {
+                for r#type in arguments {
+                    r#type.variables(variables);
+                }
+
+                result.variables(variables);
+            }
+            Self::Record(fields) => {
+                for r#type in fields.values() {
+                    r#type.variables(variables);
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |f: &mut fmt::Formatter<'_>, types: &[Self], separator| {
+            for (index, r#type) in types.iter().enumerate() {
+                if index > 0 {
+                    write!(f, "{separator}")?;
+                }
+
+                write!(f, "{}", r#type)?;
+            }
+
+            Ok(())
+        };
+
+        match self {
+            Self::Any => write!(f, "Any"),
+            Self::Named(name, parameters) => {
+                write!(f, "{name}")?;
+
+                if !parameters.is_empty() {
+                    write!(f, "<")?;
+                    join(f, parameters, ", ")?;
+                    write!(f, ">")?;
+                }
+
+                Ok(())
+            }
+            Self::Parameter(name) => write!(f, "{name}"),
+            Self::Variable(..) => write!(f, "?"),
+            Self::Arrow(arguments, result) => {
+                write!(f, "(")?;
+                join(f, arguments, ", ")?;
+                write!(f, " -> {result})")
+            }
+            Self::Tuple(elements) => {
+                write!(f, "{{")?;
+                join(f, elements, "; ")?;
+                write!(f, "}}")
+            }
+            Self::Record(fields) => {
+                write!(f, "{{")?;
+
+                for (index, (name, r#type)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{name} :: {}", r#type)?;
+                }
+
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+/// The type of a binding, which is generic over its type parameters and the
+/// variables that were left open when it was inferred.
+#[derive(Clone, Debug)]
+pub struct Scheme {
+    pub parameters: Vec<Box<str>>,
+    pub variables: Vec<usize>,
+    pub r#type: Type,
+}
+
+impl Scheme {
+    #[must_use]
+    pub const fn monomorphic(r#type: Type) -> Self {
+        Self {
+            parameters: Vec::new(),
+            variables: Vec::new(),
+            r#type,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-
+use std::rc::Rc;
 
 use crate::{PyretValue, TestResult};
 
@@ -7,21 +7,23 @@ pub enum Output {
     Print(Box<str>),
     Test {
         label: Option<Box<str>>,
+        /// The file the tests are in, which their spans point into.
+        file_id: usize,
         results: Box<[TestResult]>,
     },
 }
 
 type OutputFn = Box<dyn Fn(Output)>;
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Io {
-    output: Option<OutputFn>,
+    output: Option<Rc<OutputFn>>,
 }
 
 impl Io {
     pub fn read(&mut self, callback: OutputFn) {
         if self.output.is_none() {
-            self.output = Some(callback);
+            self.output = Some(Rc::new(callback));
         } else {
             unimplemented!()
         }
@@ -1,7 +1,8 @@
-#![feature(lazy_cell)]
-
+pub mod checker;
 pub mod io;
 pub mod ops;
+pub mod report;
+pub mod resolver;
 pub mod trove;
 pub mod value;
 #[macro_use]
@@ -10,33 +11,153 @@ pub mod macros;
 #[macro_use]
 extern crate pyret_interpreter_macros;
 
-use std::{cell::RefCell, collections::HashMap, ops::RangeInclusive, rc::Rc};
+use std::{
+    collections::HashMap,
+    fmt, iter,
+    ops::{Range, RangeInclusive},
+    rc::Rc,
+    str::FromStr,
+    sync::Arc,
+};
 
+use checker::TypeChecker;
 use io::Output;
-use pyret_error::{PyretError, PyretResult};
+use pyret_error::{PyretError, PyretErrorKind, PyretResult};
 pub use pyret_file::graph::PyretGraph;
 use pyret_lexer::ast::LetDeclarationKind;
 pub use pyret_lexer::{ast, lex, Token};
+use report::{TestBlock, TestReport};
+use resolver::Resolver;
+use trove::global::boolean::Boolean;
 use value::{
-    context::{Context, Declaration, Register, RegisteredDeclaration},
-    PyretValue, PyretValueKind,
+    context::{Context, Declaration, RegisteredDeclaration},
+    contract::{Blame, Contracts},
+    data::TypeParameters,
+    function::{Evaluate, FunctionBody, FunctionSignature, PyretBody},
+    Fields, PyretData, PyretFunction, PyretObject, PyretRef, PyretValue, PyretValueKind,
+    TypePredicate,
 };
 
+/// Which test blocks are run, from `check:`, `examples:` and `where:`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CheckMode {
+    /// Skips every test block.
+    None,
+    /// Only runs the test blocks of the file given to [`Interpreter::interpret`],
+    /// which in a REPL is the latest input.
+    Main,
+    /// Runs every test block.
+    #[default]
+    All,
+}
+
+impl FromStr for CheckMode {
+    type Err = String;
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        match mode {
+            "none" => Ok(Self::None),
+            "main" => Ok(Self::Main),
+            "all" => Ok(Self::All),
+            mode => Err(format!("expected none, main or all, found {mode}")),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct TestResult {
-    pub passed: bool,
+    pub operator: ast::BinaryOperation,
+    /// Why the test failed, or `None` if it passed.
+    pub failure: Option<TestFailure>,
     pub left_span: RangeInclusive<usize>,
-    pub left_value: PyretValue,
-    pub right_span: RangeInclusive<usize>,
-    pub right_value: PyretValue,
+    /// The value of the left side, or the message of the error it raised for
+    /// the `raises` operators. It is `None` if the left side raised otherwise.
+    pub left_value: Option<PyretValue>,
+    pub right_span: Option<RangeInclusive<usize>>,
+    pub right_value: Option<PyretValue>,
     pub test_span: RangeInclusive<usize>,
 }
 
+impl TestResult {
+    #[must_use]
+    pub const fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// The reason a test failed.
+#[derive(Debug, Clone)]
+pub enum TestFailure {
+    /// The values did not hold up to the test operator, like two different
+    /// values for `is`.
+    Mismatch,
+    /// The predicate of `satisfies`, `violates`, `is%(pred)` or the
+    /// `raises-satisfies` operators gave the wrong answer.
+    PredicateFailed,
+    /// The left side raised an error instead of evaluating to a value.
+    LeftRaised(PyretError),
+    /// The right side raised an error instead of evaluating to a value.
+    RightRaised(PyretError),
+    /// The predicate raised an error or did not return a Boolean.
+    PredicateRaised(PyretError),
+    /// The left side of a `raises` operator evaluated without an error.
+    NoErrorRaised,
+    /// The left side of `raises` or `raises-other-than` raised an error with
+    /// the wrong message.
+    WrongError(PyretError),
+}
+
+impl fmt::Display for TestFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mismatch => write!(f, "the values did not hold up to the test operator"),
+            Self::PredicateFailed => write!(f, "the predicate gave the wrong answer"),
+            Self::LeftRaised(error) => {
+                write!(f, "the left side raised an error: {}", error.kind())
+            }
+            Self::RightRaised(error) => {
+                write!(f, "the right side raised an error: {}", error.kind())
+            }
+            Self::PredicateRaised(error) => {
+                write!(f, "the predicate raised an error: {}", error.kind())
+            }
+            Self::NoErrorRaised => write!(f, "the left side did not raise an error"),
+            Self::WrongError(error) => {
+                write!(f, "the left side raised the wrong error: {}", error.kind())
+            }
+        }
+    }
+}
+
+/// How deeply the applications of functions declared in a program can nest,
+/// before a [`PyretErrorKind::RecursionLimit`] is raised instead of
+/// overflowing the stack.
+pub const MAX_CALL_DEPTH: usize = 10_000;
+
 pub struct Interpreter<G: PyretGraph> {
     pub graph: G,
     pub context: Context,
     pub provide_values: ast::ProvideValues,
     pub provide_types: ast::ProvideTypes,
+    pub check_mode: CheckMode,
+    /// Only runs the test blocks whose label contains this.
+    pub test_filter: Option<Box<str>>,
+    /// Checks the types of each file before it is interpreted, reporting the
+    /// type errors instead of running it.
+    pub type_check: bool,
     scope_level: usize,
+    /// The file being interpreted, which captured errors are reported in.
+    file_id: usize,
+    /// The file given to [`Self::interpret`], whose tests run in
+    /// [`CheckMode::Main`].
+    main_file_id: usize,
+    /// Collects the test blocks instead of writing them, while running
+    /// [`Self::run_tests`], including the blocks in the bodies of the
+    /// functions it applies.
+    test_report: Option<TestReport>,
+    /// How many applications of functions declared in the program are being
+    /// evaluated, which is limited by [`MAX_CALL_DEPTH`].
+    call_depth: usize,
 }
 
 impl<G: PyretGraph> Interpreter<G> {
@@ -47,7 +168,14 @@ impl<G: PyretGraph> Interpreter<G> {
             context: Context::default(),
             provide_values: ast::ProvideValues::Identifiers(HashMap::new()),
             provide_types: ast::ProvideTypes::Wildcard,
+            check_mode: CheckMode::default(),
+            test_filter: None,
+            type_check: false,
             scope_level: 0,
+            file_id: 0,
+            main_file_id: 0,
+            test_report: None,
+            call_depth: 0,
         }
     }
 
@@ -65,7 +193,7 @@ impl<G: PyretGraph> Interpreter<G> {
             .declarations
             .iter()
             .filter_map(|registered| match registered.declaration {
-                Declaration::Value(..) => match &self.provide_values {
+                Declaration::Value(..) | Declaration::Variable(..) => match &self.provide_values {
                     ast::ProvideValues::Wildcard => Some(registered.clone()),
                     ast::ProvideValues::Identifiers(identifiers) => identifiers
                         .get(&registered.name)
@@ -79,6 +207,9 @@ impl<G: PyretGraph> Interpreter<G> {
     }
 
     pub fn interpret(&mut self, file_id: usize) -> Result<Vec<PyretValue>, Vec<PyretError>> {
+        self.file_id = file_id;
+        self.main_file_id = file_id;
+
         let stmts = match lex(&self.graph.get(file_id).source) {
             Ok(tokens) => tokens,
             Err(errors) => {
@@ -89,12 +220,46 @@ impl<G: PyretGraph> Interpreter<G> {
             }
         };
 
+        let errors = Resolver::new(&self.context).resolve(&stmts);
+
+        if !errors.is_empty() {
+            return Err(errors
+                .into_iter()
+                .map(|error| PyretError::new(error, file_id))
+                .collect());
+        }
+
+        if self.type_check {
+            let errors = TypeChecker::default().check(&stmts);
+
+            if !errors.is_empty() {
+                return Err(errors
+                    .into_iter()
+                    .map(|error| PyretError::new(error, file_id))
+                    .collect());
+            }
+        }
+
         match self.interpret_block(stmts) {
             Ok(values) => Ok(values),
             Err(error) => Err(vec![PyretError::new(error, file_id)]),
         }
     }
 
+    /// Interprets a file and collects the results of its tests, along with the
+    /// errors that were raised outside of them, instead of writing them.
+    pub fn run_tests(&mut self, file_id: usize) -> TestReport {
+        self.test_report = Some(TestReport::default());
+
+        let errors = self.interpret(file_id).err().unwrap_or_default();
+
+        let mut report = self.test_report.take().unwrap_or_default();
+
+        report.errors = errors.into_boxed_slice();
+
+        report
+    }
+
     fn interpret_block_with<T>(
         &mut self,
         block: Vec<ast::Statement>,
@@ -102,6 +267,12 @@ impl<G: PyretGraph> Interpreter<G> {
     ) -> PyretResult<Vec<T>> {
         self.scope_level += 1;
 
+        let parent = (self.scope_level > 1).then(|| {
+            let child = self.context.child();
+
+            std::mem::replace(&mut self.context, child)
+        });
+
         let values = block
             .into_iter()
             .map(|token| interpret(self, token))
@@ -110,8 +281,8 @@ impl<G: PyretGraph> Interpreter<G> {
 
         self.scope_level -= 1;
 
-        if self.scope_level > 0 {
-            self.context.pop_scope(self.scope_level);
+        if let Some(parent) = parent {
+            self.context = parent;
         }
 
         values
@@ -129,76 +300,214 @@ impl<G: PyretGraph> Interpreter<G> {
             ast::Statement::Expression(ast::ExpressionStatement::BinaryOperator(binary_op))
                 if binary_op.operator.is_testing() =>
             {
-                let span = binary_op.start()..=binary_op.end();
+                Some(self.interpret_test(binary_op))
+            }
+            _ => {
+                self.interpret_statement(stmt)?;
+
+                None
+            }
+        })
+    }
 
-                let left_span = binary_op.left.start()..=binary_op.left.end();
-                let right_span = binary_op.right.start()..=binary_op.right.end();
+    fn interpret_test(&mut self, binary_op: ast::BinaryOperatorExpression) -> TestResult {
+        let mut result = TestResult {
+            operator: binary_op.operator.clone(),
+            failure: None,
+            left_span: binary_op.left.start()..=binary_op.left.end(),
+            left_value: None,
+            right_span: binary_op
+                .right
+                .as_ref()
+                .map(|right| right.start()..=right.end()),
+            right_value: None,
+            test_span: binary_op.start()..=binary_op.end(),
+        };
 
-                let left = self.interpret_expression(*binary_op.left)?;
-                let left_kind = &*left.kind;
-
-                let right = self.interpret_expression(*binary_op.right)?;
-                let right_kind = &*right.kind;
-
-                let result = match binary_op.operator {
-                    ast::BinaryOperation::Is => left_kind == right_kind,
-                    ast::BinaryOperation::IsRoughly => match (left_kind, right_kind) {
-                        (
-                            PyretValueKind::Number(left_number),
-                            PyretValueKind::Number(right_number),
-                        ) => left_number.is_roughly(right_number),
-                        _ => todo!("Evaluating `is roughly` on non-number values"),
-                    },
-                    _ => unreachable!(),
+        result.failure = self.evaluate_test(binary_op, &mut result).err();
+
+        result
+    }
+
+    /// Evaluates the sides of a test and checks them against its operator,
+    /// recording their values in the result as it goes.
+    fn evaluate_test(
+        &mut self,
+        binary_op: ast::BinaryOperatorExpression,
+        result: &mut TestResult,
+    ) -> Result<(), TestFailure> {
+        let file_id = self.file_id;
+        let operator = binary_op.operator;
+
+        let raised = match self.interpret_expression(*binary_op.left) {
+            Ok(value) => {
+                result.left_value = Some(value);
+
+                None
+            }
+            Err(error) if operator.is_raising() => {
+                let message = match &error {
+                    PyretErrorKind::RaiseRuntime(message) => message.clone(),
+                    error => error.to_string().into_boxed_str(),
                 };
 
-                Some(TestResult {
-                    passed: result,
-                    left_value: left,
-                    left_span,
-                    right_value: right,
-                    right_span,
-                    test_span: span,
-                })
+                result.left_value = Some(PyretValue::new(
+                    *result.left_span.start()..*result.left_span.end(),
+                    Rc::new(PyretValueKind::String(message)),
+                ));
+
+                Some(PyretError::new(error, file_id))
             }
-            _ => {
-                self.interpret_statement(stmt)?;
+            Err(error) => return Err(TestFailure::LeftRaised(PyretError::new(error, file_id))),
+        };
 
-                None
+        let refinement = binary_op
+            .refinement
+            .map(|refinement| self.interpret_expression(*refinement))
+            .transpose()
+            .map_err(|error| TestFailure::PredicateRaised(PyretError::new(error, file_id)))?;
+
+        result.right_value = binary_op
+            .right
+            .map(|right| self.interpret_expression(*right))
+            .transpose()
+            .map_err(|error| TestFailure::RightRaised(PyretError::new(error, file_id)))?;
+
+        let left = result.left_value.as_ref().unwrap();
+
+        let Some(right) = &result.right_value else {
+            // Only `does-not-raise` goes without a right side, which passes
+            // once the left side has not raised.
+            return Ok(());
+        };
+
+        if operator.is_raising() && raised.is_none() {
+            return Err(TestFailure::NoErrorRaised);
+        }
+
+        let passed = match operator {
+            ast::BinaryOperation::Is | ast::BinaryOperation::IsEqual => left.kind == right.kind,
+            ast::BinaryOperation::IsNot => left.kind != right.kind,
+            ast::BinaryOperation::IsEqualNow => ops::equal_now(&left.kind, &right.kind),
+            ast::BinaryOperation::IsIdentical => ops::identical(left, right),
+            ast::BinaryOperation::IsRoughly => ops::roughly_equal(&left.kind, &right.kind),
+            ast::BinaryOperation::IsRefinement => self.test_predicate(
+                refinement.as_ref().unwrap(),
+                vec![left.clone(), right.clone()],
+            )?,
+            ast::BinaryOperation::IsNotRefinement => !self.test_predicate(
+                refinement.as_ref().unwrap(),
+                vec![left.clone(), right.clone()],
+            )?,
+            ast::BinaryOperation::Satisfies | ast::BinaryOperation::RaisesSatisfies => {
+                self.test_predicate(right, vec![left.clone()])?
+            }
+            ast::BinaryOperation::Violates | ast::BinaryOperation::RaisesViolates => {
+                !self.test_predicate(right, vec![left.clone()])?
             }
+            ast::BinaryOperation::Raises => Self::raised_message_contains(left, right),
+            ast::BinaryOperation::RaisesOtherThan => !Self::raised_message_contains(left, right),
+            _ => unreachable!(),
+        };
+
+        if passed {
+            return Ok(());
+        }
+
+        Err(match operator {
+            ast::BinaryOperation::Raises | ast::BinaryOperation::RaisesOtherThan => {
+                TestFailure::WrongError(raised.unwrap())
+            }
+            ast::BinaryOperation::IsRefinement
+            | ast::BinaryOperation::IsNotRefinement
+            | ast::BinaryOperation::Satisfies
+            | ast::BinaryOperation::Violates
+            | ast::BinaryOperation::RaisesSatisfies
+            | ast::BinaryOperation::RaisesViolates => TestFailure::PredicateFailed,
+            _ => TestFailure::Mismatch,
         })
     }
 
+    /// Calls the predicate of a test, which has to return a Boolean.
+    fn test_predicate(
+        &mut self,
+        predicate: &PyretValue,
+        args: Vec<PyretValue>,
+    ) -> Result<bool, TestFailure> {
+        let span = predicate.span.clone().unwrap_or_default();
+
+        let context = self.context.clone();
+        let result = context.call_function(predicate, span.clone(), args, self.scope_level, self);
+
+        match result.as_ref().map(|result| &*result.kind) {
+            Ok(PyretValueKind::Boolean(boolean)) => Ok(*boolean),
+            Ok(..) => Err(PyretErrorKind::NonBooleanPredicate {
+                value: result.unwrap().to_string().into_boxed_str(),
+                predicate: span.into(),
+            }),
+            Err(..) => Err(result.err().unwrap()),
+        }
+        .map_err(|error| TestFailure::PredicateRaised(PyretError::new(error, self.file_id)))
+    }
+
+    /// Whether the message of a raised error contains the expected string, or
+    /// the display of any other expected value.
+    fn raised_message_contains(raised: &PyretValue, expected: &PyretValue) -> bool {
+        let PyretValueKind::String(message) = &*raised.kind else {
+            return false;
+        };
+
+        match &*expected.kind {
+            PyretValueKind::String(expected) => message.contains(&**expected),
+            _ => message.contains(&expected.to_string()),
+        }
+    }
+
     fn interpret_statement(&mut self, stmt: ast::Statement) -> PyretResult<Option<PyretValue>> {
         match stmt {
-            ast::Statement::Symbol(symbol) => todo!("Unexpected symbol: {symbol:?}"),
+            ast::Statement::Symbol(symbol) => Err(PyretErrorKind::Unexpected {
+                expected: Box::from(ast::Statement::NODE_NAME),
+                found: symbol.serialize(),
+            }),
             ast::Statement::Declaration(decl) => {
                 self.interpret_declaration(decl)?;
 
                 Ok(None)
             }
-            ast::Statement::Import(import) => match import.value {
-                ast::Import::As {
-                    source: _,
-                    name: ident,
-                } => {
-                    if &*ident.name != "_" {
-                        todo!()
-                    }
+            ast::Statement::Import(import) => {
+                let span = import.span();
 
-                    Ok(None)
+                match import.value {
+                    ast::Import::As {
+                        source: _,
+                        name: ident,
+                    } => {
+                        if &*ident.name != "_" {
+                            return Err(PyretErrorKind::Unsupported {
+                                feature: Box::from("importing modules"),
+                                span: span.into(),
+                            });
+                        }
+
+                        Ok(None)
+                    }
+                    ast::Import::From {
+                        names: _,
+                        source: _,
+                    } => Err(PyretErrorKind::Unsupported {
+                        feature: Box::from("importing names from modules"),
+                        span: span.into(),
+                    }),
                 }
-                ast::Import::From {
-                    names: _,
-                    source: _,
-                } => todo!(),
-            },
+            }
             ast::Statement::Expression(expr) => {
                 let expression = self.interpret_expression(expr)?;
 
                 Ok(Some(expression))
             }
             ast::Statement::Provide(provide) => {
+                let span = provide.span();
+
                 match provide.value {
                     ast::Provide::Values(values) => match values {
                         ast::ProvideValues::Wildcard => {
@@ -211,7 +520,12 @@ impl<G: PyretGraph> Interpreter<G> {
                             }
                         },
                     },
-                    ast::Provide::Types(..) => todo!(),
+                    ast::Provide::Types(..) => {
+                        return Err(PyretErrorKind::Unsupported {
+                            feature: Box::from("providing types"),
+                            span: span.into(),
+                        });
+                    }
                 };
 
                 Ok(None)
@@ -221,21 +535,36 @@ impl<G: PyretGraph> Interpreter<G> {
 
     fn interpret_expression(&mut self, expr: ast::ExpressionStatement) -> PyretResult<PyretValue> {
         match expr {
-            ast::ExpressionStatement::Application(app) => {
-                let args = app
-                    .args
-                    .into_iter()
-                    .map(|arg| self.interpret_expression(arg))
-                    .collect::<PyretResult<Vec<_>>>()?;
-
-                self.context
-                    .call_function(app.ident, args, self.scope_level)
-            }
+            ast::ExpressionStatement::Application(app) => self.interpret_application(app),
+            ast::ExpressionStatement::Assign(assign) => self.interpret_assign(assign),
             ast::ExpressionStatement::Block(block) => {
-                let values = self.interpret_block(block.body)?.into_iter();
+                let span = block.span();
 
-                Ok(values.last().unwrap())
+                self.interpret_block(block.body)?
+                    .pop()
+                    .ok_or(PyretErrorKind::BlockEndsInDeclaration { span: span.into() })
             }
+            ast::ExpressionStatement::Lambda(lambda) => Ok(PyretValue::new(
+                lambda.span(),
+                Rc::new(PyretValueKind::Function(
+                    self.create_function(Box::from("anonymous"), lambda.function),
+                )),
+            )),
+            ast::ExpressionStatement::CurlyLambda(lambda) => Ok(PyretValue::new(
+                lambda.span(),
+                Rc::new(PyretValueKind::Function(
+                    self.create_function(Box::from("anonymous"), lambda.function),
+                )),
+            )),
+            ast::ExpressionStatement::Method(method) => Ok(PyretValue::new(
+                method.span(),
+                Rc::new(PyretValueKind::Method(
+                    self.create_function(Box::from("anonymous"), method.function),
+                )),
+            )),
+            ast::ExpressionStatement::If(if_expr) => self.interpret_if(if_expr),
+            ast::ExpressionStatement::Ask(ask) => self.interpret_ask(ask),
+            ast::ExpressionStatement::Cases(cases) => self.interpret_cases(cases),
             ast::ExpressionStatement::Literal(literal) => match literal {
                 ast::LiteralExpression::Number(number) => Ok(PyretValue::new(
                     number.span(),
@@ -250,10 +579,31 @@ impl<G: PyretGraph> Interpreter<G> {
                     Rc::new(PyretValueKind::Boolean(boolean.value)),
                 )),
             },
-            ast::ExpressionStatement::Identifier(ident) => {
-                let name = &*ident.name;
+            ast::ExpressionStatement::Object(object) => {
+                let span = object.span();
 
-                let declaration = self.context.get_value(name)?;
+                let fields = self.interpret_fields(object.fields)?;
+
+                Ok(PyretValue::new(
+                    span,
+                    Rc::new(PyretValueKind::Object(PyretObject::new(fields))),
+                ))
+            }
+            ast::ExpressionStatement::Tuple(tuple) => {
+                let span = tuple.span();
+
+                let elements = self.interpret_args(tuple.elements)?;
+
+                Ok(PyretValue::new(
+                    span,
+                    Rc::new(PyretValueKind::Tuple(elements.into_boxed_slice())),
+                ))
+            }
+            ast::ExpressionStatement::TupleGet(tuple_get) => self.interpret_tuple_get(tuple_get),
+            ast::ExpressionStatement::GetBang(get_bang) => self.interpret_get_bang(get_bang),
+            ast::ExpressionStatement::Update(update) => self.interpret_update(update),
+            ast::ExpressionStatement::Identifier(ident) => {
+                let declaration = self.context.get_value(&ident)?;
 
                 Ok(PyretValue::new(
                     ident.span(),
@@ -264,20 +614,400 @@ impl<G: PyretGraph> Interpreter<G> {
                 self.interpret_binary_operator(binary_op)
             }
             ast::ExpressionStatement::Parenthesis(paren) => self.interpret_expression(*paren.expr),
-            ast::ExpressionStatement::Dot(..) => todo!(),
+            ast::ExpressionStatement::Dot(dot) => self.interpret_dot(dot),
+        }
+    }
+
+    /// Applies the function the callee evaluates to, to the arguments.
+    fn interpret_application(
+        &mut self,
+        app: ast::ApplicationExpression,
+    ) -> PyretResult<PyretValue> {
+        let callee_span = app.callee.span();
+        let function = self.interpret_expression(*app.callee)?;
+
+        let args = self.interpret_args(app.args)?;
+
+        let context = self.context.clone();
+
+        context.call_function(&function, callee_span, args, self.scope_level, self)
+    }
+
+    /// Assigns a new value to a `var` binding, checking it against the
+    /// annotation of the binding.
+    fn interpret_assign(&mut self, assign: ast::AssignExpression) -> PyretResult<PyretValue> {
+        let span = assign.span();
+
+        let variable = self.context.get_variable(&assign.ident)?;
+
+        let value = self.interpret_expression(*assign.value)?;
+
+        let value = match &variable.annotation {
+            Some(annotation) => self.type_check(
+                annotation,
+                value,
+                format!("the new value of {}", assign.ident.name),
+            )?,
+            None => value,
+        };
+
+        *variable.cell.borrow_mut() = PyretValue::new(assign.ident.span(), value.kind);
+
+        Ok(PyretValue::new(span, Rc::new(PyretValueKind::Nothing)))
+    }
+
+    fn interpret_if(&mut self, if_expr: ast::IfExpression) -> PyretResult<PyretValue> {
+        let span = if_expr.span();
+
+        match self.interpret_branches(if_expr.branches)? {
+            Some(value) => Ok(value),
+            None => match if_expr.else_body {
+                Some(body) => self.interpret_body(body),
+                None => Err(PyretErrorKind::NoBranchesMatched {
+                    expression: Box::from("if"),
+                    span: span.into(),
+                }),
+            },
+        }
+    }
+
+    fn interpret_ask(&mut self, ask: ast::AskExpression) -> PyretResult<PyretValue> {
+        let span = ask.span();
+
+        match self.interpret_branches(ask.branches)? {
+            Some(value) => Ok(value),
+            None => match ask.otherwise {
+                Some(body) => self.interpret_body(body),
+                None => Err(PyretErrorKind::NoBranchesMatched {
+                    expression: Box::from("ask"),
+                    span: span.into(),
+                }),
+            },
+        }
+    }
+
+    /// Evaluates the body of the first branch whose condition is `true`, if any.
+    fn interpret_branches(
+        &mut self,
+        branches: Vec<ast::ConditionalBranch>,
+    ) -> PyretResult<Option<PyretValue>> {
+        for branch in branches {
+            let span = branch.condition.span();
+            let condition = self.interpret_expression(branch.condition)?;
+
+            if !(Boolean::predicate())(condition.clone(), self.context.clone()) {
+                return Err(PyretErrorKind::NonBooleanCondition {
+                    value: condition.to_string().into_boxed_str(),
+                    condition: span.into(),
+                });
+            }
+
+            if *condition.kind == PyretValueKind::Boolean(true) {
+                return self.interpret_body(branch.body).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Looks up a field of an object or an instance of a data variant.
+    fn interpret_dot(&mut self, dot: ast::DotExpression) -> PyretResult<PyretValue> {
+        let span = dot.span();
+        let object_span = dot.object.span();
+
+        let object = self.interpret_expression(*dot.object)?;
+
+        let field = object.get_field(&dot.property, object_span)?;
+
+        let kind = match &*field.kind {
+            PyretValueKind::Method(method) => {
+                Rc::new(PyretValueKind::Function(method.bind(object)))
+            }
+            _ => field.kind,
+        };
+
+        Ok(PyretValue::new(span, kind))
+    }
+
+    fn interpret_get_bang(&mut self, get_bang: ast::GetBangExpression) -> PyretResult<PyretValue> {
+        let span = get_bang.span();
+        let object_span = get_bang.object.span();
+
+        let object = self.interpret_expression(*get_bang.object)?;
+
+        let reference = object.get_ref(&get_bang.property, object_span)?;
+
+        Ok(PyretValue::new(span, reference.get().kind))
+    }
+
+    /// Updates the `ref` fields of an object, checking each new value against
+    /// the annotation of its field.
+    fn interpret_update(&mut self, update: ast::UpdateExpression) -> PyretResult<PyretValue> {
+        let object_span = update.object.span();
+
+        let object = self.interpret_expression(*update.object)?;
+
+        for field in update.fields {
+            let reference = object.get_ref(&field.key, object_span.clone())?;
+
+            let value_span = field.value.span();
+            let value = self.interpret_expression(field.value)?;
+
+            let satisfied = match &reference.annotation {
+                Some(annotation) => {
+                    let context = self.context.clone();
+
+                    context.check_annotation(annotation, value.clone(), self)?
+                }
+                None => true,
+            };
+
+            if !satisfied {
+                return Err(PyretErrorKind::InvalidRefUpdate {
+                    field: field.key.name,
+                    value: value.to_string().into_boxed_str(),
+                    span: value_span.into(),
+                });
+            }
+
+            reference.set(PyretValue::new(field.key.span(), value.kind));
+        }
+
+        Ok(object)
+    }
+
+    fn interpret_tuple_get(
+        &mut self,
+        tuple_get: ast::TupleGetExpression,
+    ) -> PyretResult<PyretValue> {
+        let span = tuple_get.span();
+        let tuple_span = tuple_get.tuple.span();
+
+        let tuple = self.interpret_expression(*tuple_get.tuple)?;
+
+        let PyretValueKind::Tuple(elements) = &*tuple.kind else {
+            return Err(PyretErrorKind::NotATuple {
+                value: tuple.to_string().into_boxed_str(),
+                tuple: tuple_span.into(),
+            });
+        };
+
+        match elements.get(tuple_get.index) {
+            Some(element) => Ok(PyretValue::new(span, Rc::clone(&element.kind))),
+            None => Err(PyretErrorKind::TupleIndexOutOfBounds {
+                index: tuple_get.index,
+                length: elements.len(),
+                span: span.into(),
+            }),
+        }
+    }
+
+    /// Binds each element of a tuple to the corresponding name of a tuple
+    /// binding, where `span` is the span of the expression the tuple was
+    /// evaluated from, and `shadows` is whether the binding was declared with
+    /// `shadow`.
+    fn bind_tuple(
+        &mut self,
+        binding: ast::TupleBinding,
+        value: PyretValue,
+        span: Range<usize>,
+        shadows: bool,
+    ) -> PyretResult<()> {
+        let PyretValueKind::Tuple(elements) = &*value.kind else {
+            return Err(PyretErrorKind::NotATuple {
+                value: value.to_string().into_boxed_str(),
+                tuple: span.into(),
+            });
+        };
+
+        if elements.len() != binding.bindings.len() {
+            return Err(PyretErrorKind::TupleArityMismatch {
+                expected: binding.bindings.len(),
+                found: elements.len(),
+                span: span.into(),
+            });
+        }
+
+        for (binding, element) in binding.bindings.into_iter().zip(elements.iter()) {
+            match binding {
+                ast::LetBinding::Name(ident) => self.context.register_local_expr(
+                    ident.name.clone(),
+                    Some(PyretValue::new(ident.span(), Rc::clone(&element.kind))),
+                    ident.span(),
+                    self.scope_level,
+                    shadows,
+                )?,
+                ast::LetBinding::Tuple(binding) => {
+                    let span = element.span.clone().unwrap_or_else(|| binding.span());
+
+                    self.bind_tuple(binding, element.clone(), span, shadows)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn interpret_args(
+        &mut self,
+        args: Vec<ast::ExpressionStatement>,
+    ) -> PyretResult<Vec<PyretValue>> {
+        args.into_iter()
+            .map(|arg| self.interpret_expression(arg))
+            .collect()
+    }
+
+    /// Evaluates the fields of an object, naming methods after their keys.
+    fn interpret_fields(&mut self, fields: Vec<ast::ObjectField>) -> PyretResult<Fields> {
+        fields
+            .into_iter()
+            .map(|field| {
+                let value = match field.value {
+                    ast::ExpressionStatement::Method(method) => PyretValue::new(
+                        method.span(),
+                        Rc::new(PyretValueKind::Method(
+                            self.create_function(field.key.name.clone(), method.function),
+                        )),
+                    ),
+                    value => self.interpret_expression(value)?,
+                };
+
+                let value = if field.is_ref {
+                    PyretValue::new(
+                        field.key.span(),
+                        Rc::new(PyretValueKind::Ref(PyretRef::new(value, None))),
+                    )
+                } else {
+                    value
+                };
+
+                Ok((field.key.name, value))
+            })
+            .collect()
+    }
+
+    /// Evaluates the body of the branch matching the variant of the value, in
+    /// a scope with its fields bound.
+    fn interpret_cases(&mut self, cases: ast::CasesExpression) -> PyretResult<PyretValue> {
+        let span = cases.span();
+
+        let value = self.interpret_expression(*cases.value)?;
+
+        let value = self.type_check(
+            &cases.annotation,
+            value,
+            String::from("the value examined by cases"),
+        )?;
+
+        if let PyretValueKind::Data(data) = &*value.kind {
+            if let Some(branch) = cases
+                .branches
+                .iter()
+                .find(|branch| !data.variants.contains(&branch.ident.name))
+            {
+                return Err(PyretErrorKind::CasesUnknownVariant {
+                    ident: branch.ident.name.clone(),
+                    type_name: data.type_name.clone(),
+                    span: branch.ident.span().into(),
+                });
+            }
+
+            if let Some(branch) = cases
+                .branches
+                .into_iter()
+                .find(|branch| branch.ident.name == data.variant)
+            {
+                let fields = data.fields.as_deref().unwrap_or_default();
+                let bindings = branch.bindings.unwrap_or_default();
+
+                if bindings.len() != fields.len() {
+                    return Err(PyretErrorKind::CasesArityMismatch {
+                        variant: data.variant.clone(),
+                        expected: fields.len(),
+                        found: bindings.len(),
+                        span: branch.ident.span().into(),
+                    });
+                }
+
+                let child = self.context.child();
+                let parent = std::mem::replace(&mut self.context, child);
+
+                for (binding, (_, field)) in bindings.into_iter().zip(fields) {
+                    if &*binding.ident.name != "_" {
+                        let kind = match &*field.kind {
+                            PyretValueKind::Ref(reference) => reference.get().kind,
+                            _ => Rc::clone(&field.kind),
+                        };
+
+                        let value = PyretValue::new(binding.ident.span(), kind);
+
+                        let value = match &binding.annotation {
+                            Some(annotation) => self.type_check(
+                                annotation,
+                                value,
+                                format!("the field bound to {}", binding.ident.name),
+                            )?,
+                            None => value,
+                        };
+
+                        self.context.register_local_expr(
+                            binding.ident.name.clone(),
+                            Some(PyretValue::new(binding.ident.span(), value.kind)),
+                            binding.ident.span(),
+                            self.scope_level + 1,
+                            binding.shadows,
+                        )?;
+                    }
+                }
+
+                let value = self.interpret_body(branch.body);
+
+                self.context = parent;
+
+                return value;
+            }
+        }
+
+        match cases.else_body {
+            Some(body) => self.interpret_body(body),
+            None => Err(PyretErrorKind::NoCasesBranchMatched {
+                variant: match &*value.kind {
+                    PyretValueKind::Data(data) => data.variant.clone(),
+                    _ => value.to_string().into_boxed_str(),
+                },
+                span: span.into(),
+            }),
         }
     }
 
+    /// Evaluates a block body, resulting in its last value or `nothing`.
+    fn interpret_body(&mut self, body: Vec<ast::Statement>) -> PyretResult<PyretValue> {
+        Ok(self
+            .interpret_block(body)?
+            .pop()
+            .unwrap_or_else(|| PyretValue::from(PyretValueKind::Nothing)))
+    }
+
     fn interpret_binary_operator(
         &mut self,
         binary_op: ast::BinaryOperatorExpression,
     ) -> PyretResult<PyretValue> {
-        match binary_op.operator {
-            ast::BinaryOperation::And => ops::and(*binary_op.left, *binary_op.right, self),
-            ast::BinaryOperation::Or => ops::or(*binary_op.left, *binary_op.right, self),
+        let span = binary_op.span();
+
+        if binary_op.operator.is_testing() {
+            return Err(PyretErrorKind::TestOutsideCheck { span: span.into() });
+        }
+
+        let right = *binary_op
+            .right
+            .expect("only testing operators go without a right side");
+
+        let value = match binary_op.operator {
+            ast::BinaryOperation::And => ops::and(*binary_op.left, right, self),
+            ast::BinaryOperation::Or => ops::or(*binary_op.left, right, self),
             _ => {
                 let left = self.interpret_expression(*binary_op.left)?;
-                let right = self.interpret_expression(*binary_op.right)?;
+                let right = self.interpret_expression(right)?;
 
                 match binary_op.operator {
                     ast::BinaryOperation::Plus => ops::plus(left, right),
@@ -292,81 +1022,476 @@ impl<G: PyretGraph> Interpreter<G> {
                     }
                     ast::BinaryOperation::Equal => ops::equal(left, right),
                     ast::BinaryOperation::NotEqual => ops::not_equal(left, right),
-                    ast::BinaryOperation::Is | ast::BinaryOperation::IsRoughly => {
-                        todo!(
-                            "The testing statement is not inside a check, where or examples block."
-                        )
-                    }
                     _ => unreachable!(),
                 }
             }
-        }
+        }?;
+
+        Ok(PyretValue::new(span, value.kind))
     }
 
     fn interpret_declaration(&mut self, decl: ast::DeclarationStatement) -> PyretResult<()> {
         match decl {
             ast::DeclarationStatement::Check(check) => {
-                let label = check.label;
+                let span = check.span();
 
-                let results = self
-                    .interpret_block_with(check.body, Self::interpret_test_statement)?
-                    .into_iter()
-                    .collect::<Box<[TestResult]>>();
-
-                self.context
-                    .borrow_mut()
-                    .io
-                    .write(Output::Test { label, results });
-            }
-            ast::DeclarationStatement::Let(var) => {
-                if var.kind == LetDeclarationKind::RecursiveLet {
-                    self.context.register_local_expr(
-                        var.ident.name.clone(),
-                        None,
-                        self.scope_level,
-                    );
+                self.interpret_check(check.label, span, check.body)?;
+            }
+            ast::DeclarationStatement::Data(data) => self.interpret_data(data)?,
+            ast::DeclarationStatement::Function(decl) => {
+                let span = decl.span();
+                let function = self.create_function(decl.ident.name.clone(), decl.function);
+
+                self.context.register_local_expr(
+                    decl.ident.name.clone(),
+                    Some(PyretValue::new(
+                        decl.ident.span(),
+                        Rc::new(PyretValueKind::Function(function)),
+                    )),
+                    decl.ident.span(),
+                    self.scope_level,
+                    false,
+                )?;
+
+                if let Some(where_block) = decl.where_block {
+                    self.interpret_check(Some(decl.ident.name), span, where_block)?;
                 }
+            }
+            ast::DeclarationStatement::Let(var) => match var.binding {
+                ast::LetBinding::Name(ident) => {
+                    if var.kind == LetDeclarationKind::RecursiveLet {
+                        self.context.register_local_expr(
+                            ident.name.clone(),
+                            None,
+                            ident.span(),
+                            self.scope_level,
+                            var.shadows,
+                        )?;
+                    }
 
-                let value = var
-                    .init
-                    .map(|init| {
-                        let expr = self.interpret_expression(init)?;
+                    let value = var
+                        .init
+                        .map(|init| self.interpret_expression(init))
+                        .transpose()?;
 
-                        Ok(PyretValue::new(var.ident.span(), Rc::clone(&expr.kind)))
-                    })
-                    .transpose()?;
+                    let value = match (&var.annotation, value) {
+                        (Some(annotation), Some(value)) => Some(self.type_check(
+                            annotation,
+                            value,
+                            format!("the value of {}", ident.name),
+                        )?),
+                        (_, value) => value,
+                    };
 
-                if let (Some(annotation), Some(value)) = (var.annotation, &value) {
-                    self.type_check(&annotation, value.clone())?;
+                    let value = value.map(|value| PyretValue::new(ident.span(), value.kind));
+
+                    if var.kind == LetDeclarationKind::Variable {
+                        let value = value.unwrap_or_else(|| {
+                            PyretValue::new(ident.span(), Rc::new(PyretValueKind::Nothing))
+                        });
+
+                        self.context.register_local_variable(
+                            ident.name,
+                            value,
+                            var.annotation,
+                            self.scope_level,
+                            var.shadows,
+                        )?;
+                    } else {
+                        let span = ident.span();
+
+                        self.context.register_local_expr(
+                            ident.name,
+                            value,
+                            span,
+                            self.scope_level,
+                            var.shadows,
+                        )?;
+                    }
                 }
+                ast::LetBinding::Tuple(binding) => {
+                    if let Some(init) = var.init {
+                        let span = init.span();
+                        let value = self.interpret_expression(init)?;
+
+                        self.bind_tuple(binding, value, span, var.shadows)?;
+                    }
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Runs the tests of a `check:`, `examples:` or `where:` block, and writes
+    /// their results.
+    fn interpret_check(
+        &mut self,
+        label: Option<Box<str>>,
+        span: Range<usize>,
+        body: Vec<ast::Statement>,
+    ) -> PyretResult<()> {
+        let enabled = match self.check_mode {
+            CheckMode::None => false,
+            CheckMode::Main => self.main_file_id == self.file_id,
+            CheckMode::All => true,
+        };
+
+        if !enabled {
+            return Ok(());
+        }
 
-                self.context
-                    .register_local_expr(var.ident.name, value, self.scope_level);
+        if let Some(filter) = &self.test_filter {
+            if !label
+                .as_deref()
+                .is_some_and(|label| label.contains(&**filter))
+            {
+                return Ok(());
             }
         }
 
+        let results = self
+            .interpret_block_with(body, Self::interpret_test_statement)?
+            .into_iter()
+            .collect::<Box<[TestResult]>>();
+
+        if let Some(report) = &mut self.test_report {
+            report.push(TestBlock::new(label, span, results));
+
+            return Ok(());
+        }
+
+        self.context.borrow_mut().io.write(Output::Test {
+            label,
+            file_id: self.file_id,
+            results,
+        });
+
         Ok(())
     }
 
-    fn type_check(&self, annotation: &ast::TypeAnnotation, value: PyretValue) -> PyretResult<()> {
-        match &annotation.value {
-            ast::AnnotationType::NameAnnotation {
-                name,
-                parameters: _,
-                predicate: _,
-            } => match name {
-                ast::IdentifierAnnotation::Name(ident) => {
-                    if let Some(r#type) = self.context.get_type(&ident.name)? {
-                        if !r#type(value, self.context.clone()) {
-                            todo!("Type error: {annotation:?}")
-                        }
-                    }
+    /// Registers the type of a `data` declaration, along with the constructors
+    /// and predicates of its variants.
+    fn interpret_data(&mut self, data: ast::DataDeclaration) -> PyretResult<()> {
+        let type_name = data.ident.name.clone();
 
-                    Ok(())
-                }
-                ast::IdentifierAnnotation::Dot(..) => todo!("Type annotation: {annotation:?}"),
-            },
-            _ => todo!("Type annotation: {annotation:?}"),
+        let generic_types = data
+            .generics
+            .into_iter()
+            .map(|ident| ident.name)
+            .collect::<Box<[_]>>();
+
+        let type_predicate: TypePredicate = {
+            let type_name = type_name.clone();
+
+            Arc::new(
+                move |value, _context| matches!(&*value.kind, PyretValueKind::Data(data) if data.type_name == type_name),
+            )
+        };
+
+        self.context.register_local_type(
+            type_name.clone(),
+            Arc::clone(&type_predicate),
+            data.ident.span(),
+            self.scope_level,
+        )?;
+
+        self.register_predicate(
+            format!("is-{type_name}").into_boxed_str(),
+            data.ident.span(),
+            Arc::clone(&type_predicate),
+        )?;
+
+        let shared = self.interpret_fields(data.shared)?;
+
+        let variant_names: Rc<[Box<str>]> = data
+            .variants
+            .iter()
+            .map(|variant| variant.ident.name.clone())
+            .collect();
+
+        for variant in data.variants {
+            let variant_name = variant.ident.name.clone();
+
+            let members: Rc<Fields> = Rc::new(
+                self.interpret_fields(variant.members)?
+                    .into_vec()
+                    .into_iter()
+                    .chain(shared.iter().cloned())
+                    .collect(),
+            );
+
+            let variant_predicate: TypePredicate = {
+                let type_name = type_name.clone();
+                let variant_name = variant_name.clone();
+
+                Arc::new(move |value, _context| {
+                    matches!(
+                        &*value.kind,
+                        PyretValueKind::Data(data)
+                            if data.type_name == type_name && data.variant == variant_name
+                    )
+                })
+            };
+
+            self.register_predicate(
+                format!("is-{variant_name}").into_boxed_str(),
+                variant.ident.span(),
+                variant_predicate,
+            )?;
+
+            let value = if let Some(fields) = variant.fields {
+                let param_types = iter::repeat_with(trove::global::Any::predicate)
+                    .take(fields.len())
+                    .collect::<Box<[_]>>();
+
+                let ref_types = fields
+                    .iter()
+                    .map(|field| {
+                        field.is_ref.then(|| {
+                            field
+                                .annotation
+                                .as_ref()
+                                .map(|annotation| Rc::new(annotation.value.clone()))
+                        })
+                    })
+                    .collect::<Box<[_]>>();
+
+                let parameters = (!generic_types.is_empty()).then(|| {
+                    Rc::new(TypeParameters {
+                        names: generic_types.clone(),
+                        fields: fields
+                            .iter()
+                            .map(|field| {
+                                field
+                                    .annotation
+                                    .as_ref()
+                                    .filter(|_| !field.is_ref)
+                                    .map(|annotation| annotation.value.clone())
+                            })
+                            .collect(),
+                    })
+                });
+
+                let contracts = Contracts {
+                    params: fields
+                        .iter()
+                        .map(|field| field.annotation.clone())
+                        .collect(),
+                    result: None,
+                };
+
+                let field_names = fields
+                    .into_iter()
+                    .map(|field| field.ident.name)
+                    .collect::<Box<[_]>>();
+
+                let type_name = type_name.clone();
+                let constructed_variant = variant_name.clone();
+                let variant_names = Rc::clone(&variant_names);
+
+                let body: FunctionSignature = Rc::new(move |args, _context| {
+                    let fields = field_names
+                        .iter()
+                        .cloned()
+                        .zip(
+                            args.zip(ref_types.iter())
+                                .map(|(arg, ref_type)| match ref_type {
+                                    Some(annotation) => PyretValue::from(PyretValueKind::Ref(
+                                        PyretRef::new(arg, annotation.clone()),
+                                    )),
+                                    None => arg,
+                                }),
+                        )
+                        .collect();
+
+                    Ok(PyretValue::from(PyretValueKind::Data(PyretData::new(
+                        type_name.clone(),
+                        constructed_variant.clone(),
+                        Rc::clone(&variant_names),
+                        Some(fields),
+                        Rc::clone(&members),
+                        parameters.clone(),
+                    ))))
+                });
+
+                PyretValueKind::Function(
+                    PyretFunction::new(
+                        variant_name.clone(),
+                        generic_types.clone(),
+                        param_types,
+                        Arc::clone(&type_predicate),
+                        body,
+                        self.context.clone(),
+                    )
+                    .with_contracts(contracts),
+                )
+            } else {
+                let parameters = (!generic_types.is_empty()).then(|| {
+                    Rc::new(TypeParameters {
+                        names: generic_types.clone(),
+                        fields: Box::default(),
+                    })
+                });
+
+                PyretValueKind::Data(PyretData::new(
+                    type_name.clone(),
+                    variant_name.clone(),
+                    Rc::clone(&variant_names),
+                    None,
+                    members,
+                    parameters,
+                ))
+            };
+
+            self.context.register_local_expr(
+                variant_name,
+                Some(PyretValue::new(variant.ident.span(), Rc::new(value))),
+                variant.ident.span(),
+                self.scope_level,
+                false,
+            )?;
         }
+
+        Ok(())
+    }
+
+    /// Registers a function named `name` that checks whether its argument
+    /// satisfies the predicate.
+    fn register_predicate(
+        &self,
+        name: Box<str>,
+        span: Range<usize>,
+        predicate: TypePredicate,
+    ) -> PyretResult<()> {
+        let body: FunctionSignature = Rc::new(move |args, context| {
+            let value = args.next().unwrap();
+
+            Ok(PyretValue::from(PyretValueKind::Boolean(predicate(
+                value, context,
+            ))))
+        });
+
+        let function = PyretFunction::new(
+            name.clone(),
+            Box::from_iter([]),
+            Box::from_iter([trove::global::Any::predicate()]),
+            Boolean::predicate(),
+            body,
+            self.context.clone(),
+        );
+
+        self.context.register_local_expr(
+            name,
+            Some(PyretValue::new(
+                span.clone(),
+                Rc::new(PyretValueKind::Function(function)),
+            )),
+            span,
+            self.scope_level,
+            false,
+        )
+    }
+
+    /// Creates a function value that captures the current context, in which
+    /// its body will be evaluated.
+    fn create_function(&self, name: Box<str>, function: ast::Function) -> PyretFunction {
+        let generic_types = function
+            .generics
+            .into_iter()
+            .map(|ident| ident.name)
+            .collect();
+
+        let param_types = iter::repeat_with(trove::global::Any::predicate)
+            .take(function.params.len())
+            .collect();
+
+        let contracts = Contracts {
+            params: function
+                .params
+                .iter()
+                .map(|param| param.annotation.clone())
+                .collect(),
+            result: function.return_annotation,
+        };
+
+        let body = PyretBody {
+            params: function.params,
+            block: function.body,
+            file_id: self.file_id,
+        };
+
+        PyretFunction::with_body(
+            name,
+            generic_types,
+            param_types,
+            trove::global::Any::predicate(),
+            FunctionBody::Pyret(Rc::new(body)),
+            self.context.clone(),
+        )
+        .with_contracts(contracts)
+    }
+
+    /// Checks a value against an annotation, blaming `party` if it does not
+    /// satisfy it, and results in the value, where functions are wrapped to
+    /// check their arguments and results.
+    fn type_check(
+        &mut self,
+        annotation: &ast::TypeAnnotation,
+        value: PyretValue,
+        party: String,
+    ) -> PyretResult<PyretValue> {
+        let blame = Blame::new(party, annotation.span(), annotation.span());
+
+        let context = self.context.clone();
+
+        context.check_contract(&annotation.value, value, &blame, self)
+    }
+}
+
+impl<G: PyretGraph> Evaluate for Interpreter<G> {
+    /// Evaluates the body in the context of the application, which replaces
+    /// the context of the interpreter until the body has been evaluated.
+    fn evaluate(
+        &mut self,
+        body: &PyretBody,
+        args: Vec<PyretValue>,
+        span: Range<usize>,
+        context: Context,
+    ) -> PyretResult<PyretValue> {
+        if self.call_depth >= MAX_CALL_DEPTH {
+            return Err(PyretErrorKind::RecursionLimit {
+                limit: MAX_CALL_DEPTH,
+                span: span.into(),
+            });
+        }
+
+        for (param, arg) in body.params.iter().zip(args) {
+            context.register_local_expr(
+                param.ident.name.clone(),
+                Some(PyretValue::new(param.ident.span(), arg.kind)),
+                param.ident.span(),
+                1,
+                param.shadows,
+            )?;
+        }
+
+        let context = std::mem::replace(&mut self.context, context);
+        let scope_level = std::mem::replace(&mut self.scope_level, 0);
+        let file_id = std::mem::replace(&mut self.file_id, body.file_id);
+
+        self.call_depth += 1;
+
+        let values = self.interpret_block(body.block.clone());
+
+        self.call_depth -= 1;
+
+        self.context = context;
+        self.scope_level = scope_level;
+        self.file_id = file_id;
+
+        Ok(values?
+            .pop()
+            .unwrap_or_else(|| PyretValue::from(PyretValueKind::Nothing)))
     }
 }
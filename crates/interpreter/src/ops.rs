@@ -1,8 +1,8 @@
 use std::{convert::Into, rc::Rc};
 
 use pyret_error::{PyretErrorKind, PyretGraph, PyretResult};
-use pyret_lexer::ast::ExpressionStatement;
-use pyret_number::Zero;
+use pyret_lexer::{ast::ExpressionStatement, Token};
+use pyret_number::{PyretNumber, Zero};
 
 use crate::{
     value::{PyretValue, PyretValueKind},
@@ -24,19 +24,21 @@ pub fn plus(left: PyretValue, right: PyretValue) -> PyretResult<PyretValue> {
                 string.into_boxed_str(),
             )))
         }
-        _ => todo!(),
+        _ => Err(invalid_operands(
+            "+",
+            "two Numbers or two Strings",
+            &left,
+            &right,
+        )),
     }
 }
 
 pub fn minus(left: PyretValue, right: PyretValue) -> PyretResult<PyretValue> {
     match (&*left.kind, &*right.kind) {
-        (PyretValueKind::Number(left_number), PyretValueKind::Number(right_number)) => {
-            Ok(PyretValue::new(
-                left.span.as_ref().unwrap().start..right.span.as_ref().unwrap().end,
-                Rc::new(PyretValueKind::Number(left_number - right_number)),
-            ))
-        }
-        _ => todo!(),
+        (PyretValueKind::Number(left_number), PyretValueKind::Number(right_number)) => Ok(
+            PyretValue::from(PyretValueKind::Number(left_number - right_number)),
+        ),
+        _ => Err(invalid_operands("-", "two Numbers", &left, &right)),
     }
 }
 
@@ -45,7 +47,7 @@ pub fn times(left: PyretValue, right: PyretValue) -> PyretResult<PyretValue> {
         (PyretValueKind::Number(left_number), PyretValueKind::Number(right_number)) => Ok(
             PyretValue::from(PyretValueKind::Number(left_number * right_number)),
         ),
-        _ => todo!(),
+        _ => Err(invalid_operands("*", "two Numbers", &left, &right)),
     }
 }
 
@@ -62,7 +64,7 @@ pub fn divide(left: PyretValue, right: PyretValue) -> PyretResult<PyretValue> {
                 )))
             }
         }
-        _ => todo!(),
+        _ => Err(invalid_operands("/", "two Numbers", &left, &right)),
     }
 }
 
@@ -74,7 +76,12 @@ pub fn less_than(left: PyretValue, right: PyretValue) -> PyretResult<PyretValue>
         (PyretValueKind::String(left_string), PyretValueKind::String(right_string)) => Ok(
             PyretValue::from(PyretValueKind::Boolean(left_string < right_string)),
         ),
-        _ => todo!(),
+        _ => Err(invalid_operands(
+            "<",
+            "two Numbers or two Strings",
+            &left,
+            &right,
+        )),
     }
 }
 
@@ -86,7 +93,12 @@ pub fn less_than_or_equal(left: PyretValue, right: PyretValue) -> PyretResult<Py
         (PyretValueKind::String(left_string), PyretValueKind::String(right_string)) => Ok(
             PyretValue::from(PyretValueKind::Boolean(left_string <= right_string)),
         ),
-        _ => todo!(),
+        _ => Err(invalid_operands(
+            "<=",
+            "two Numbers or two Strings",
+            &left,
+            &right,
+        )),
     }
 }
 
@@ -98,7 +110,12 @@ pub fn greater_than(left: PyretValue, right: PyretValue) -> PyretResult<PyretVal
         (PyretValueKind::String(left_string), PyretValueKind::String(right_string)) => Ok(
             PyretValue::from(PyretValueKind::Boolean(left_string > right_string)),
         ),
-        _ => todo!(),
+        _ => Err(invalid_operands(
+            ">",
+            "two Numbers or two Strings",
+            &left,
+            &right,
+        )),
     }
 }
 
@@ -110,7 +127,12 @@ pub fn greater_than_or_equal(left: PyretValue, right: PyretValue) -> PyretResult
         (PyretValueKind::String(left_string), PyretValueKind::String(right_string)) => Ok(
             PyretValue::from(PyretValueKind::Boolean(left_string >= right_string)),
         ),
-        _ => todo!(),
+        _ => Err(invalid_operands(
+            ">=",
+            "two Numbers or two Strings",
+            &left,
+            &right,
+        )),
     }
 }
 
@@ -125,6 +147,15 @@ pub fn equal(left: PyretValue, right: PyretValue) -> PyretResult<PyretValue> {
         (PyretValueKind::Boolean(left_boolean), PyretValueKind::Boolean(right_boolean)) => Ok(
             PyretValue::from(PyretValueKind::Boolean(left_boolean == right_boolean)),
         ),
+        (PyretValueKind::Data(left_data), PyretValueKind::Data(right_data)) => Ok(
+            PyretValue::from(PyretValueKind::Boolean(left_data == right_data)),
+        ),
+        (PyretValueKind::Object(left_object), PyretValueKind::Object(right_object)) => Ok(
+            PyretValue::from(PyretValueKind::Boolean(left_object == right_object)),
+        ),
+        (PyretValueKind::Tuple(..), PyretValueKind::Tuple(..)) => Ok(PyretValue::from(
+            PyretValueKind::Boolean(left.kind == right.kind),
+        )),
         _ => Ok(PyretValue::from(PyretValueKind::Boolean(false))),
     }
 }
@@ -140,6 +171,15 @@ pub fn not_equal(left: PyretValue, right: PyretValue) -> PyretResult<PyretValue>
         (PyretValueKind::Boolean(left_boolean), PyretValueKind::Boolean(right_boolean)) => Ok(
             PyretValue::from(PyretValueKind::Boolean(left_boolean != right_boolean)),
         ),
+        (PyretValueKind::Data(left_data), PyretValueKind::Data(right_data)) => Ok(
+            PyretValue::from(PyretValueKind::Boolean(left_data != right_data)),
+        ),
+        (PyretValueKind::Object(left_object), PyretValueKind::Object(right_object)) => Ok(
+            PyretValue::from(PyretValueKind::Boolean(left_object != right_object)),
+        ),
+        (PyretValueKind::Tuple(..), PyretValueKind::Tuple(..)) => Ok(PyretValue::from(
+            PyretValueKind::Boolean(left.kind != right.kind),
+        )),
         _ => Ok(PyretValue::from(PyretValueKind::Boolean(true))),
     }
 }
@@ -149,16 +189,15 @@ pub fn and<G: PyretGraph>(
     right: ExpressionStatement,
     interpreter: &mut Interpreter<G>,
 ) -> PyretResult<PyretValue> {
-    match &*interpreter.interpret_expression(left)?.kind {
-        PyretValueKind::Boolean(left) => Ok(PyretValue::from(PyretValueKind::Boolean(
-            *left
-                && match &*interpreter.interpret_expression(right)?.kind {
-                    PyretValueKind::Boolean(right) => *right,
-                    _ => todo!("Evaluating `and` on non-boolean values"),
-                },
-        ))),
-        _ => todo!("Evaluating `and` on non-boolean values"),
+    if !boolean_operand("and", left, interpreter)? {
+        return Ok(PyretValue::from(PyretValueKind::Boolean(false)));
     }
+
+    Ok(PyretValue::from(PyretValueKind::Boolean(boolean_operand(
+        "and",
+        right,
+        interpreter,
+    )?)))
 }
 
 pub fn or<G: PyretGraph>(
@@ -166,14 +205,127 @@ pub fn or<G: PyretGraph>(
     right: ExpressionStatement,
     interpreter: &mut Interpreter<G>,
 ) -> PyretResult<PyretValue> {
-    match &*interpreter.interpret_expression(left)?.kind {
-        PyretValueKind::Boolean(left) => Ok(PyretValue::from(PyretValueKind::Boolean(
-            *left
-                || match &*interpreter.interpret_expression(right)?.kind {
-                    PyretValueKind::Boolean(right) => *right,
-                    _ => todo!("Evaluating `or` on non-boolean values"),
-                },
-        ))),
-        _ => todo!("Evaluating `or` on non-boolean values"),
+    if boolean_operand("or", left, interpreter)? {
+        return Ok(PyretValue::from(PyretValueKind::Boolean(true)));
+    }
+
+    Ok(PyretValue::from(PyretValueKind::Boolean(boolean_operand(
+        "or",
+        right,
+        interpreter,
+    )?)))
+}
+
+/// Evaluates an operand of `and` or `or`, which must be a Boolean.
+fn boolean_operand<G: PyretGraph>(
+    operator: &str,
+    operand: ExpressionStatement,
+    interpreter: &mut Interpreter<G>,
+) -> PyretResult<bool> {
+    let span = operand.span();
+
+    let value = interpreter.interpret_expression(operand)?;
+
+    match &*value.kind {
+        PyretValueKind::Boolean(boolean) => Ok(*boolean),
+        _ => Err(PyretErrorKind::NonBooleanOperand {
+            operator: Box::from(operator),
+            value: value.to_string().into_boxed_str(),
+            span: span.into(),
+        }),
+    }
+}
+
+fn invalid_operands(
+    operator: &str,
+    expected: &str,
+    left: &PyretValue,
+    right: &PyretValue,
+) -> PyretErrorKind {
+    PyretErrorKind::InvalidOperands {
+        operator: Box::from(operator),
+        expected: Box::from(expected),
+        left: left.to_string().into_boxed_str(),
+        right: right.to_string().into_boxed_str(),
+        left_span: left.span.clone().map(Into::into),
+        right_span: right.span.clone().map(Into::into),
+    }
+}
+
+/// Whether two values are the same value, like Pyret's `identical`, where
+/// only primitive values are compared by their contents.
+#[must_use]
+pub fn identical(left: &PyretValue, right: &PyretValue) -> bool {
+    match (&*left.kind, &*right.kind) {
+        (PyretValueKind::Number(..), PyretValueKind::Number(..))
+        | (PyretValueKind::String(..), PyretValueKind::String(..))
+        | (PyretValueKind::Boolean(..), PyretValueKind::Boolean(..))
+        | (PyretValueKind::Nothing, PyretValueKind::Nothing) => left.kind == right.kind,
+        _ => Rc::ptr_eq(&left.kind, &right.kind),
+    }
+}
+
+/// Compares two values by their current contents, like Pyret's `equal-now`,
+/// which looks through references instead of comparing their identity.
+#[must_use]
+pub fn equal_now(left: &PyretValueKind, right: &PyretValueKind) -> bool {
+    structural_equal(left, right, &|left, right| left == right, true)
+}
+
+/// Compares two values like `is`, but where numbers only need to be within a
+/// relative tolerance of each other, like Pyret's `within`.
+#[must_use]
+pub fn roughly_equal(left: &PyretValueKind, right: &PyretValueKind) -> bool {
+    structural_equal(left, right, &PyretNumber::is_roughly, false)
+}
+
+fn structural_equal(
+    left: &PyretValueKind,
+    right: &PyretValueKind,
+    numbers: &dyn Fn(&PyretNumber, &PyretNumber) -> bool,
+    through_refs: bool,
+) -> bool {
+    let equal = |left: &PyretValue, right: &PyretValue| {
+        structural_equal(&left.kind, &right.kind, numbers, through_refs)
+    };
+
+    match (left, right) {
+        (PyretValueKind::Number(left_number), PyretValueKind::Number(right_number)) => {
+            numbers(left_number, right_number)
+        }
+        (PyretValueKind::Data(left_data), PyretValueKind::Data(right_data)) => {
+            left_data.type_name == right_data.type_name
+                && left_data.variant == right_data.variant
+                && match (&left_data.fields, &right_data.fields) {
+                    (Some(left_fields), Some(right_fields)) => {
+                        left_fields.len() == right_fields.len()
+                            && left_fields
+                                .iter()
+                                .zip(right_fields.iter())
+                                .all(|((_, left), (_, right))| equal(left, right))
+                    }
+                    (None, None) => true,
+                    _ => false,
+                }
+        }
+        (PyretValueKind::Object(left_object), PyretValueKind::Object(right_object)) => {
+            left_object.fields.len() == right_object.fields.len()
+                && left_object.fields.iter().all(|(key, left)| {
+                    right_object
+                        .get(key)
+                        .is_some_and(|right| equal(left, right))
+                })
+        }
+        (PyretValueKind::Tuple(left_elements), PyretValueKind::Tuple(right_elements)) => {
+            left_elements.len() == right_elements.len()
+                && left_elements
+                    .iter()
+                    .zip(right_elements.iter())
+                    .all(|(left, right)| equal(left, right))
+        }
+        (PyretValueKind::Ref(left_ref), PyretValueKind::Ref(right_ref)) if through_refs => {
+            equal(&left_ref.get(), &right_ref.get())
+        }
+        _ => left == right,
     }
 }
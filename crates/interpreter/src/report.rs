@@ -0,0 +1,399 @@
+use std::{
+    error::Error,
+    fmt,
+    ops::{Range, RangeInclusive},
+};
+
+use pyret_error::{
+    miette::{Diagnostic, LabeledSpan, Report},
+    PyretError, PyretErrorKind, PyretFile,
+};
+
+use crate::{
+    ast::BinaryOperation,
+    ops,
+    value::{PyretValue, PyretValueKind},
+    TestFailure, TestResult,
+};
+
+/// Strings longer than this are compared line by line instead of being shown
+/// inline.
+const LONG_STRING: usize = 60;
+
+/// The results of the tests of a file, along with the errors that were
+/// raised outside of them.
+#[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TestReport {
+    pub blocks: Vec<TestBlock>,
+    pub passed: usize,
+    pub failed: usize,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_errors"))]
+    pub errors: Box<[PyretError]>,
+}
+
+impl TestReport {
+    pub fn push(&mut self, block: TestBlock) {
+        self.passed += block.passed;
+        self.failed += block.failed;
+
+        self.blocks.push(block);
+    }
+
+    /// Whether every test passed, without any errors outside of them.
+    #[must_use]
+    pub fn succeeded(&self) -> bool {
+        self.failed == 0 && self.errors.is_empty()
+    }
+}
+
+/// The results of a `check:`, `examples:` or `where:` block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TestBlock {
+    pub label: Option<Box<str>>,
+    pub span: Range<usize>,
+    pub results: Box<[TestResult]>,
+    pub passed: usize,
+    pub failed: usize,
+}
+
+impl TestBlock {
+    #[must_use]
+    pub fn new(label: Option<Box<str>>, span: Range<usize>, results: Box<[TestResult]>) -> Self {
+        let passed = results.iter().filter(|result| result.passed()).count();
+        let failed = results.len() - passed;
+
+        Self {
+            label,
+            span,
+            results,
+            passed,
+            failed,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for TestResult {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let display = |value: &Option<PyretValue>| value.as_ref().map(ToString::to_string);
+
+        let mut state = serializer.serialize_struct("TestResult", 8)?;
+
+        state.serialize_field("operator", self.operator.symbol())?;
+        state.serialize_field("passed", &self.passed())?;
+        state.serialize_field("failure", &self.failure.as_ref().map(ToString::to_string))?;
+        state.serialize_field("left_span", &self.left_span)?;
+        state.serialize_field("left_value", &display(&self.left_value))?;
+        state.serialize_field("right_span", &self.right_span)?;
+        state.serialize_field("right_value", &display(&self.right_value))?;
+        state.serialize_field("test_span", &self.test_span)?;
+
+        state.end()
+    }
+}
+
+/// Serializes errors by their messages, since their labels point into source
+/// that is not part of the report.
+#[cfg(feature = "serde")]
+fn serialize_errors<S: serde::Serializer>(
+    errors: &[PyretError],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(errors.iter().map(|error| error.kind().to_string()))
+}
+
+/// A diagnostic for a failed test, which highlights its operands in the source
+/// along with their values, and shows how the values differ.
+#[derive(Debug)]
+pub struct TestFailureReport {
+    message: Box<str>,
+    labels: Vec<LabeledSpan>,
+    help: Option<Box<str>>,
+    /// The error that was captured while running the test.
+    error: Option<PyretErrorKind>,
+}
+
+impl TestFailureReport {
+    /// Renders the result of a test from `source`, or `None` if it passed.
+    #[must_use]
+    pub fn new(result: TestResult, source: &str) -> Option<Self> {
+        let failure = result.failure?;
+
+        let operator = result.operator;
+
+        let left_end = result
+            .right_span
+            .as_ref()
+            .map_or(*result.test_span.end(), |right| *right.start());
+
+        let mut labels = vec![LabeledSpan::underline(operator_span(
+            source,
+            *result.left_span.end(),
+            left_end,
+        ))];
+
+        let left_label = match (&failure, &result.left_value) {
+            (TestFailure::LeftRaised(..), _) => Box::from("this raised an error"),
+            (_, Some(left))
+                if operator.is_raising() && !matches!(failure, TestFailure::NoErrorRaised) =>
+            {
+                match &*left.kind {
+                    PyretValueKind::String(message) => format!("this raised {message}"),
+                    _ => format!("this raised {left}"),
+                }
+                .into_boxed_str()
+            }
+            (_, Some(left)) => describe("this", left),
+            (_, None) => Box::from("this side"),
+        };
+
+        labels.push(label(left_label, &result.left_span));
+
+        // The right side is not evaluated once the left side raised.
+        if let Some(right_span) = &result.right_span {
+            match (&failure, &result.right_value) {
+                (TestFailure::RightRaised(..), _) => {
+                    labels.push(label(Box::from("this raised an error"), right_span));
+                }
+                (_, Some(right)) => labels.push(label(describe("this", right), right_span)),
+                (_, None) => {}
+            }
+        }
+
+        let help = match (&failure, &result.left_value, &result.right_value) {
+            (TestFailure::Mismatch, Some(left), Some(right)) => value_diff(&operator, left, right),
+            _ => None,
+        };
+
+        let message =
+            format!("This `{}` test failed because {failure}", operator.symbol()).into_boxed_str();
+
+        let error = match failure {
+            TestFailure::LeftRaised(error)
+            | TestFailure::RightRaised(error)
+            | TestFailure::PredicateRaised(error)
+            | TestFailure::WrongError(error) => Some(error.into_kind()),
+            TestFailure::Mismatch | TestFailure::PredicateFailed | TestFailure::NoErrorRaised => {
+                None
+            }
+        };
+
+        Some(Self {
+            message,
+            labels,
+            help,
+            error,
+        })
+    }
+
+    #[must_use]
+    pub fn into_report(self, file: &PyretFile) -> Report {
+        Report::new(self).with_source_code(PyretFile::new(file.name.clone(), file.source.clone()))
+    }
+}
+
+impl fmt::Display for TestFailureReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for TestFailureReport {}
+
+impl Diagnostic for TestFailureReport {
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(self.labels.iter().cloned()))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn fmt::Display>)
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.error.as_ref().map(|error| {
+            Box::new(std::iter::once(error as &dyn Diagnostic)) as Box<dyn Iterator<Item = _>>
+        })
+    }
+}
+
+fn label(text: Box<str>, span: &RangeInclusive<usize>) -> LabeledSpan {
+    LabeledSpan::new_with_span(Some(text.into_string()), *span.start()..*span.end())
+}
+
+/// Finds the operator between the end of the left side and the start of the
+/// right side, including the predicate of `is%(pred)`.
+fn operator_span(source: &str, left_end: usize, right_start: usize) -> std::ops::Range<usize> {
+    let between = source.get(left_end..right_start).unwrap_or_default();
+
+    let start = left_end + (between.len() - between.trim_start().len());
+    let end = left_end + between.trim_end().len();
+
+    start..end.max(start)
+}
+
+/// Describes a value in a label, leaving long values to the diff.
+fn describe(subject: &str, value: &PyretValue) -> Box<str> {
+    let display = value.to_string();
+
+    if display.len() > LONG_STRING || display.contains('\n') {
+        format!("{subject} side")
+    } else {
+        format!("{subject} evaluated to {display}")
+    }
+    .into_boxed_str()
+}
+
+/// Shows how the values of a failed comparison differ, either line by line
+/// for long strings or by the paths where nested values differ.
+fn value_diff(
+    operator: &BinaryOperation,
+    left: &PyretValue,
+    right: &PyretValue,
+) -> Option<Box<str>> {
+    if *operator == BinaryOperation::IsNot {
+        return None;
+    }
+
+    if let (PyretValueKind::String(left), PyretValueKind::String(right)) =
+        (&*left.kind, &*right.kind)
+    {
+        let long = |string: &str| string.len() > LONG_STRING || string.contains('\n');
+
+        return (long(left) || long(right)).then(|| {
+            let mut help = String::from("the strings differ (- left, + right):");
+
+            for line in line_diff(left, right) {
+                help.push('\n');
+                help.push_str(&line);
+            }
+
+            help.into_boxed_str()
+        });
+    }
+
+    let equal: fn(&PyretValueKind, &PyretValueKind) -> bool = match operator {
+        BinaryOperation::IsRoughly => ops::roughly_equal,
+        BinaryOperation::IsEqualNow => ops::equal_now,
+        _ => |left, right| left == right,
+    };
+
+    let mut differences = Vec::new();
+
+    structural_diff(left, right, equal, &mut String::new(), &mut differences);
+
+    (!differences.is_empty()).then(|| {
+        let mut help = String::from("the values differ at:");
+
+        for difference in differences {
+            help.push_str("\n  ");
+            help.push_str(&difference);
+        }
+
+        help.into_boxed_str()
+    })
+}
+
+/// Compares the lines of two strings by their longest common subsequence.
+fn line_diff(left: &str, right: &str) -> Vec<String> {
+    let left = left.lines().collect::<Vec<_>>();
+    let right = right.lines().collect::<Vec<_>>();
+
+    let mut lengths = vec![vec![0_usize; right.len() + 1]; left.len() + 1];
+
+    for i in (0..left.len()).rev() {
+        for j in (0..right.len()).rev() {
+            lengths[i][j] = if left[i] == right[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < left.len() && j < right.len() {
+        if left[i] == right[j] {
+            lines.push(format!("  {}", left[i]));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            lines.push(format!("- {}", left[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", right[j]));
+            j += 1;
+        }
+    }
+
+    lines.extend(left[i..].iter().map(|line| format!("- {line}")));
+    lines.extend(right[j..].iter().map(|line| format!("+ {line}")));
+
+    lines
+}
+
+/// Collects the paths where nested data, objects and tuples differ, which are
+/// left out when the values differ as a whole.
+fn structural_diff(
+    left: &PyretValue,
+    right: &PyretValue,
+    equal: fn(&PyretValueKind, &PyretValueKind) -> bool,
+    path: &mut String,
+    differences: &mut Vec<String>,
+) {
+    if equal(&left.kind, &right.kind) {
+        return;
+    }
+
+    let mut descend = |key: &str, left: &PyretValue, right: &PyretValue| {
+        let length = path.len();
+
+        path.push_str(key);
+        structural_diff(left, right, equal, path, differences);
+        path.truncate(length);
+    };
+
+    match (&*left.kind, &*right.kind) {
+        (PyretValueKind::Data(left_data), PyretValueKind::Data(right_data))
+            if left_data.type_name == right_data.type_name
+                && left_data.variant == right_data.variant =>
+        {
+            if let (Some(left_fields), Some(right_fields)) = (&left_data.fields, &right_data.fields)
+            {
+                for ((key, left), (_, right)) in left_fields.iter().zip(right_fields.iter()) {
+                    descend(&format!(".{key}"), left, right);
+                }
+            }
+        }
+        (PyretValueKind::Object(left_object), PyretValueKind::Object(right_object))
+            if left_object.fields.len() == right_object.fields.len()
+                && left_object
+                    .fields
+                    .iter()
+                    .all(|(key, _)| right_object.get(key).is_some()) =>
+        {
+            for (key, left) in left_object.fields.iter() {
+                if let Some(right) = right_object.get(key) {
+                    descend(&format!(".{key}"), left, right);
+                }
+            }
+        }
+        (PyretValueKind::Tuple(left_elements), PyretValueKind::Tuple(right_elements))
+            if left_elements.len() == right_elements.len() =>
+        {
+            for (index, (left, right)) in
+                left_elements.iter().zip(right_elements.iter()).enumerate()
+            {
+                descend(&format!(".{{{index}}}"), left, right);
+            }
+        }
+        _ if !path.is_empty() => differences.push(format!("{path}: {left} is not {right}")),
+        _ => {}
+    }
+}
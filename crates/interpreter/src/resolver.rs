@@ -0,0 +1,626 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+use pyret_error::PyretErrorKind;
+use pyret_lexer::{
+    ast::{self, LetDeclarationKind},
+    Token,
+};
+
+use crate::value::context::{declaration_span, Context, Declaration};
+
+/// A name declared in a scope.
+struct Declared {
+    /// Where the name was declared, which is unknown for builtins.
+    span: Option<Range<usize>>,
+    is_builtin: bool,
+    /// Whether the declaration has been reached, after which the name can be
+    /// used by the rest of the scope.
+    is_defined: bool,
+}
+
+struct Scope {
+    names: HashMap<Box<str>, Declared>,
+    /// The types declared in the scope, which annotations can name.
+    types: HashSet<Box<str>>,
+    /// How many functions enclose the scope.
+    depth: usize,
+}
+
+/// Resolves the names of a program before it is interpreted, collecting the
+/// unbound names and types, duplicate declarations, names used before they
+/// are defined and declarations that shadow another without `shadow`.
+///
+/// Like a `letrec`, the functions and data of a block are declared throughout
+/// it, so they can be used within functions before they are reached. Other
+/// names are only declared from where they are bound.
+pub struct Resolver {
+    scopes: Vec<Scope>,
+    depth: usize,
+    errors: Vec<PyretErrorKind>,
+}
+
+impl Resolver {
+    /// Creates a resolver for a program that is interpreted in `context`,
+    /// whose declarations can be used by the program as if it declared them.
+    #[must_use]
+    pub fn new(context: &Context) -> Self {
+        let (types, values): (Vec<_>, Vec<_>) = context
+            .declarations()
+            .into_iter()
+            .partition(|(_, declaration)| matches!(declaration, Declaration::Type(..)));
+
+        let names = values
+            .into_iter()
+            .map(|(name, declaration)| {
+                let is_builtin =
+                    matches!(&declaration, Declaration::Value(Some(scoped)) if scoped.is_builtin);
+
+                (
+                    name,
+                    Declared {
+                        span: declaration_span(&declaration),
+                        is_builtin,
+                        is_defined: true,
+                    },
+                )
+            })
+            .collect();
+
+        let types = types.into_iter().map(|(name, _)| name).collect();
+
+        Self {
+            scopes: vec![Scope {
+                names,
+                types,
+                depth: 0,
+            }],
+            depth: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Resolves a program, resulting in the errors it contains.
+    #[must_use]
+    pub fn resolve(mut self, program: &[ast::Statement]) -> Vec<PyretErrorKind> {
+        // The program shares the scope of the context, like the interpreter
+        // registers its top-level declarations alongside the builtins.
+        self.declare_block(program);
+
+        for stmt in program {
+            self.resolve_statement(stmt);
+        }
+
+        self.errors
+    }
+
+    fn resolve_block(&mut self, block: &[ast::Statement]) {
+        self.push_scope();
+        self.declare_block(block);
+
+        for stmt in block {
+            self.resolve_statement(stmt);
+        }
+
+        self.scopes.pop();
+    }
+
+    fn push_scope(&mut self) {
+        self.scopes.push(Scope {
+            names: HashMap::new(),
+            types: HashSet::new(),
+            depth: self.depth,
+        });
+    }
+
+    /// Declares the type parameters of a function, `data` declaration or
+    /// arrow annotation in the innermost scope.
+    fn declare_types(&mut self, generics: &[ast::IdentifierExpression]) {
+        let scope = self.scopes.last_mut().unwrap();
+
+        scope
+            .types
+            .extend(generics.iter().map(|ident| ident.name.clone()));
+    }
+
+    /// Declares the functions and data of a block before it is resolved, as
+    /// they can be used anywhere in it, while the other names of the block
+    /// are declared once their bindings are reached.
+    fn declare_block(&mut self, block: &[ast::Statement]) {
+        for stmt in block {
+            match stmt {
+                ast::Statement::Declaration(ast::DeclarationStatement::Data(data)) => {
+                    let scope = self.scopes.last_mut().unwrap();
+
+                    scope.types.insert(data.ident.name.clone());
+
+                    self.declare(&format!("is-{}", data.ident.name), data.ident.span(), false);
+
+                    for variant in &data.variants {
+                        let span = variant.ident.span();
+
+                        self.declare(&format!("is-{}", variant.ident.name), span.clone(), false);
+                        self.declare(&variant.ident.name, span, false);
+                    }
+                }
+                ast::Statement::Declaration(ast::DeclarationStatement::Function(declaration)) => {
+                    self.declare(&declaration.ident.name, declaration.ident.span(), false);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Declares a name in the innermost scope, where `shadows` is whether it
+    /// was declared with `shadow`.
+    fn declare(&mut self, name: &str, span: Range<usize>, shadows: bool) {
+        if name == "_" {
+            return;
+        }
+
+        let (scope, outer) = self.scopes.split_last_mut().unwrap();
+
+        let error = match scope.names.get(name) {
+            Some(previous) if previous.is_builtin => {
+                (!shadows).then(|| PyretErrorKind::ShadowsBuiltin {
+                    ident: Box::from(name),
+                    span: span.clone().into(),
+                })
+            }
+            Some(previous) => Some(PyretErrorKind::DuplicateDeclaration {
+                ident: Box::from(name),
+                span: span.clone().into(),
+                previous: previous.span.clone().map(Into::into),
+            }),
+            None if shadows => None,
+            None => outer
+                .iter()
+                .rev()
+                .find_map(|scope| scope.names.get(name))
+                .map(|previous| {
+                    if previous.is_builtin {
+                        PyretErrorKind::ShadowsBuiltin {
+                            ident: Box::from(name),
+                            span: span.clone().into(),
+                        }
+                    } else {
+                        PyretErrorKind::ShadowedDeclaration {
+                            ident: Box::from(name),
+                            span: span.clone().into(),
+                            previous: previous.span.clone().map(Into::into),
+                        }
+                    }
+                }),
+        };
+
+        self.errors.extend(error);
+
+        scope.names.insert(
+            Box::from(name),
+            Declared {
+                span: Some(span),
+                is_builtin: false,
+                is_defined: false,
+            },
+        );
+    }
+
+    /// Marks a declared name of the innermost scope as defined.
+    fn define(&mut self, name: &str) {
+        if let Some(declared) = self
+            .scopes
+            .last_mut()
+            .and_then(|scope| scope.names.get_mut(name))
+        {
+            declared.is_defined = true;
+        }
+    }
+
+    /// Declares and defines a name in the innermost scope, once its binding
+    /// is reached.
+    fn bind(&mut self, ident: &ast::IdentifierExpression, shadows: bool) {
+        self.declare(&ident.name, ident.span(), shadows);
+        self.define(&ident.name);
+    }
+
+    /// Declares and defines the parameters of a function or the bindings of
+    /// a `cases` branch in the innermost scope.
+    fn bind_parameters(&mut self, params: &[ast::Parameter]) {
+        for param in params {
+            if let Some(annotation) = &param.annotation {
+                self.resolve_annotation(&annotation.value);
+            }
+
+            self.bind(&param.ident, param.shadows);
+        }
+    }
+
+    /// Resolves a use of a name.
+    fn resolve_name(&mut self, ident: &ast::IdentifierExpression) {
+        let found = self
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| Some((scope.names.get(&ident.name)?, scope.depth)));
+
+        match found {
+            Some((declared, depth)) if !declared.is_defined && depth == self.depth => {
+                if let Some(definition) = &declared.span {
+                    self.errors.push(PyretErrorKind::UsedBeforeDefinition {
+                        ident: ident.name.clone(),
+                        span: ident.span().into(),
+                        definition: definition.clone().into(),
+                    });
+                }
+            }
+            Some(..) => {}
+            None => self.errors.push(PyretErrorKind::UnboundIdentifier {
+                ident: ident.name.clone(),
+                span: ident.span().into(),
+            }),
+        }
+    }
+
+    /// Resolves the names of the types in an annotation, along with its
+    /// predicates, which are only applied once a value is checked against it.
+    fn resolve_annotation(&mut self, annotation: &ast::AnnotationType) {
+        match annotation {
+            ast::AnnotationType::NameAnnotation {
+                name,
+                parameters,
+                predicate,
+            } => {
+                match name {
+                    ast::IdentifierAnnotation::Name(ident) => {
+                        if !self
+                            .scopes
+                            .iter()
+                            .any(|scope| scope.types.contains(&ident.name))
+                        {
+                            self.errors.push(PyretErrorKind::UnboundType {
+                                ident: ident.name.clone(),
+                                span: Some(ident.span().into()),
+                            });
+                        }
+                    }
+                    ast::IdentifierAnnotation::Dot(names) => {
+                        let errors = self.errors.len();
+
+                        self.resolve_name(&names[0]);
+
+                        // Modules do not provide types yet, so once the module
+                        // is found, the type named through it is unbound.
+                        if self.errors.len() == errors {
+                            self.errors.push(PyretErrorKind::UnboundType {
+                                ident: names
+                                    .iter()
+                                    .map(|ident| &*ident.name)
+                                    .collect::<Vec<_>>()
+                                    .join(".")
+                                    .into_boxed_str(),
+                                span: Some((names[0].start()..names[names.len() - 1].end()).into()),
+                            });
+                        }
+                    }
+                }
+
+                for parameter in parameters {
+                    self.resolve_annotation(parameter);
+                }
+
+                if let Some(predicate) = predicate {
+                    self.depth += 1;
+                    self.resolve_name(predicate);
+                    self.depth -= 1;
+                }
+            }
+            ast::AnnotationType::ArrowAnnotation {
+                generics,
+                arguments,
+                return_annotation,
+            } => {
+                self.push_scope();
+                self.declare_types(generics.as_deref().unwrap_or_default());
+
+                for argument in arguments {
+                    self.resolve_annotation(argument);
+                }
+
+                self.resolve_annotation(return_annotation);
+
+                self.scopes.pop();
+            }
+            ast::AnnotationType::TupleAnnotation(elements) => {
+                for element in elements {
+                    self.resolve_annotation(element);
+                }
+            }
+            ast::AnnotationType::RecordAnnotation(fields) => {
+                for field in fields.values() {
+                    self.resolve_annotation(field);
+                }
+            }
+        }
+    }
+
+    fn resolve_statement(&mut self, stmt: &ast::Statement) {
+        match stmt {
+            ast::Statement::Declaration(decl) => self.resolve_declaration(decl),
+            ast::Statement::Import(import) => match &import.value {
+                ast::Import::As { name, .. } => self.bind(name, false),
+                ast::Import::From { names, .. } => {
+                    for name in names.iter() {
+                        self.bind(name, false);
+                    }
+                }
+            },
+            ast::Statement::Expression(expr) => self.resolve_expression(expr),
+            ast::Statement::Symbol(..) | ast::Statement::Provide(..) => {}
+        }
+    }
+
+    fn resolve_declaration(&mut self, decl: &ast::DeclarationStatement) {
+        match decl {
+            ast::DeclarationStatement::Check(check) => self.resolve_block(&check.body),
+            ast::DeclarationStatement::Data(data) => {
+                self.define(&format!("is-{}", data.ident.name));
+
+                for variant in &data.variants {
+                    self.define(&format!("is-{}", variant.ident.name));
+                    self.define(&variant.ident.name);
+                }
+
+                self.push_scope();
+                self.declare_types(&data.generics);
+
+                for variant in &data.variants {
+                    for field in variant.fields.iter().flatten() {
+                        if let Some(annotation) = &field.annotation {
+                            self.resolve_annotation(&annotation.value);
+                        }
+                    }
+                }
+
+                self.scopes.pop();
+
+                for member in data
+                    .variants
+                    .iter()
+                    .flat_map(|variant| &variant.members)
+                    .chain(&data.shared)
+                {
+                    self.resolve_expression(&member.value);
+                }
+            }
+            ast::DeclarationStatement::Function(declaration) => {
+                self.define(&declaration.ident.name);
+                self.resolve_function(&declaration.function);
+
+                if let Some(where_block) = &declaration.where_block {
+                    self.resolve_block(where_block);
+                }
+            }
+            ast::DeclarationStatement::Let(declaration) => {
+                if let Some(annotation) = &declaration.annotation {
+                    self.resolve_annotation(&annotation.value);
+                }
+
+                let names = binding_names(&declaration.binding);
+
+                // A `rec` binding can be used within its own value, as long
+                // as that is only once a function in it is called.
+                let is_recursive = declaration.kind == LetDeclarationKind::RecursiveLet;
+
+                if is_recursive {
+                    for ident in &names {
+                        self.declare(&ident.name, ident.span(), declaration.shadows);
+                    }
+                }
+
+                if let Some(init) = &declaration.init {
+                    self.resolve_expression(init);
+                }
+
+                for ident in names {
+                    if is_recursive {
+                        self.define(&ident.name);
+                    } else {
+                        self.bind(ident, declaration.shadows);
+                    }
+                }
+            }
+        }
+    }
+
+    fn resolve_function(&mut self, function: &ast::Function) {
+        self.depth += 1;
+        self.push_scope();
+        self.declare_types(&function.generics);
+
+        self.bind_parameters(&function.params);
+
+        if let Some(annotation) = &function.return_annotation {
+            self.resolve_annotation(&annotation.value);
+        }
+
+        self.resolve_block(&function.body);
+
+        self.scopes.pop();
+        self.depth -= 1;
+    }
+
+    fn resolve_expression(&mut self, expr: &ast::ExpressionStatement) {
+        match expr {
+            ast::ExpressionStatement::Application(app) => {
+                self.resolve_expression(&app.callee);
+
+                for arg in &app.args {
+                    self.resolve_expression(arg);
+                }
+            }
+            ast::ExpressionStatement::Assign(assign) => {
+                self.resolve_expression(&assign.value);
+                self.resolve_name(&assign.ident);
+            }
+            ast::ExpressionStatement::Block(block) => self.resolve_block(&block.body),
+            ast::ExpressionStatement::Lambda(ast::LambdaExpression { function, .. })
+            | ast::ExpressionStatement::CurlyLambda(ast::CurlyLambdaExpression {
+                function, ..
+            }) => self.resolve_function(function),
+            ast::ExpressionStatement::Method(method) => self.resolve_function(&method.function),
+            ast::ExpressionStatement::If(if_expr) => {
+                self.resolve_branches(&if_expr.branches, if_expr.else_body.as_deref());
+            }
+            ast::ExpressionStatement::Ask(ask) => {
+                self.resolve_branches(&ask.branches, ask.otherwise.as_deref());
+            }
+            ast::ExpressionStatement::Cases(cases) => {
+                self.resolve_annotation(&cases.annotation.value);
+                self.resolve_expression(&cases.value);
+
+                for branch in &cases.branches {
+                    self.push_scope();
+                    self.bind_parameters(branch.bindings.as_deref().unwrap_or_default());
+                    self.resolve_block(&branch.body);
+                    self.scopes.pop();
+                }
+
+                if let Some(else_body) = &cases.else_body {
+                    self.resolve_block(else_body);
+                }
+            }
+            ast::ExpressionStatement::Literal(..) => {}
+            ast::ExpressionStatement::Object(object) => {
+                for field in &object.fields {
+                    self.resolve_expression(&field.value);
+                }
+            }
+            ast::ExpressionStatement::Tuple(tuple) => {
+                for element in &tuple.elements {
+                    self.resolve_expression(element);
+                }
+            }
+            ast::ExpressionStatement::TupleGet(tuple_get) => {
+                self.resolve_expression(&tuple_get.tuple);
+            }
+            ast::ExpressionStatement::BinaryOperator(binary_op) => {
+                self.resolve_expression(&binary_op.left);
+
+                if let Some(refinement) = &binary_op.refinement {
+                    self.resolve_expression(refinement);
+                }
+
+                if let Some(right) = &binary_op.right {
+                    self.resolve_expression(right);
+                }
+            }
+            ast::ExpressionStatement::Identifier(ident) => self.resolve_name(ident),
+            ast::ExpressionStatement::Dot(dot) => self.resolve_expression(&dot.object),
+            ast::ExpressionStatement::GetBang(get_bang) => {
+                self.resolve_expression(&get_bang.object);
+            }
+            ast::ExpressionStatement::Update(update) => {
+                self.resolve_expression(&update.object);
+
+                for field in &update.fields {
+                    self.resolve_expression(&field.value);
+                }
+            }
+            ast::ExpressionStatement::Parenthesis(parenthesis) => {
+                self.resolve_expression(&parenthesis.expr);
+            }
+        }
+    }
+
+    fn resolve_branches(
+        &mut self,
+        branches: &[ast::ConditionalBranch],
+        else_body: Option<&[ast::Statement]>,
+    ) {
+        for branch in branches {
+            self.resolve_expression(&branch.condition);
+            self.resolve_block(&branch.body);
+        }
+
+        if let Some(else_body) = else_body {
+            self.resolve_block(else_body);
+        }
+    }
+}
+
+/// Collects the names bound by a `let` binding, including those nested in
+/// tuple bindings.
+fn binding_names(binding: &ast::LetBinding) -> Vec<&ast::IdentifierExpression> {
+    match binding {
+        ast::LetBinding::Name(ident) => vec![ident],
+        ast::LetBinding::Tuple(tuple) => tuple.bindings.iter().flat_map(binding_names).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pyret_error::PyretErrorKind;
+
+    use super::Resolver;
+    use crate::{lex, trove, Context};
+
+    fn resolve(source: &str) -> Vec<PyretErrorKind> {
+        let context = Context::default();
+
+        trove::import_trove("global", context.clone()).unwrap();
+
+        Resolver::new(&context).resolve(&lex(source).unwrap())
+    }
+
+    #[test]
+    fn lambda_parameter_before_binding() {
+        assert!(resolve("fun make-adder(n): lam(x): x + n end end\nx = 3").is_empty());
+        assert!(resolve("f = {(x): x * 2}\nx = 3").is_empty());
+    }
+
+    #[test]
+    fn block_binding_before_binding() {
+        assert!(resolve("block:\n  v = 1\n  v\nend\nv = 2").is_empty());
+    }
+
+    #[test]
+    fn parameter_after_binding() {
+        let errors = resolve("x = 3\nfun f(x): x end");
+
+        assert!(matches!(
+            errors[..],
+            [PyretErrorKind::ShadowedDeclaration { .. }]
+        ));
+    }
+
+    #[test]
+    fn function_used_before_declaration() {
+        assert!(resolve("fun f(): g() end\nfun g(): 1 end").is_empty());
+        assert_eq!(resolve("fun f(): y end\ny = 1").len(), 1);
+    }
+
+    #[test]
+    fn unbound_type() {
+        let errors = resolve("x :: Foo = 1");
+
+        assert!(matches!(errors[..], [PyretErrorKind::UnboundType { .. }]));
+        assert!(resolve("data D<T>: | d(v :: T) end\nx :: D<Number> = d(1)").is_empty());
+        assert!(resolve("x :: Boolean = true\nf :: (Number -> Boolean) = is-number").is_empty());
+    }
+
+    #[test]
+    fn dotted_type() {
+        let errors = resolve("x :: L.List = 1");
+
+        assert!(matches!(
+            errors[..],
+            [PyretErrorKind::UnboundIdentifier { .. }]
+        ));
+
+        let errors = resolve("import lists as L\nx :: L.List = 1");
+
+        assert!(matches!(errors[..], [PyretErrorKind::UnboundType { .. }]));
+    }
+}
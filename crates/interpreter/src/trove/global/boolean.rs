@@ -12,6 +12,8 @@ use crate::{
 
 #[inline]
 pub fn register(context: Context) -> PyretResult<()> {
+    Boolean::register(context.clone())?;
+
     ModBoolean::register(context)
 }
 
@@ -58,9 +58,12 @@ pub fn register(context: Context) -> PyretResult<()> {
             "raise",
             [any],
             Rc::new(|args, _context| {
-                Err(PyretErrorKind::RaiseRuntime(
-                    args.next().unwrap().to_string().into_boxed_str(),
-                ))
+                let value = args.next().unwrap();
+
+                Err(PyretErrorKind::RaiseRuntime(match &*value.kind {
+                    PyretValueKind::String(message) => message.clone(),
+                    _ => value.to_string().into_boxed_str(),
+                }))
             }),
         )?;
     }
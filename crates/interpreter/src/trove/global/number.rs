@@ -3,7 +3,7 @@ use std::{cmp::Ordering, rc::Rc, sync::Arc};
 use pyret_error::PyretErrorKind;
 use pyret_number::{PyretNumber, Signed};
 
-use super::{boolean::Boolean, Any};
+use super::Any;
 use crate::{
     ty,
     value::{context::Context, PyretValueKind},
@@ -499,33 +499,3 @@ ty!(NumNonNegative, |value, _context| matches!(
     &*value.kind,
     PyretValueKind::Number(number) if number.is_non_negative()
 ));
-
-struct ModNumber;
-
-#[module]
-impl ModNumber {
-    #[inline]
-    pub fn is_number(value: &Number) -> Boolean {
-        Boolean(PyretValue::from(PyretValueKind::Boolean(matches!(
-            *value.kind,
-            PyretValueKind::Number(..)
-        ))))
-    }
-
-    #[inline]
-    pub fn num_max(left: Number, right: Number) -> PyretResult<Number> {
-        let rc_left = left.0;
-        let rc_right = right.0;
-
-        match (&*rc_left.kind, &*rc_right.kind) {
-            (PyretValueKind::Number(left), PyretValueKind::Number(right)) => {
-                match left.partial_cmp(right) {
-                    Some(Ordering::Less | Ordering::Equal) => Ok(Number(rc_left)),
-                    Some(Ordering::Greater) => Ok(Number(rc_right)),
-                    None => Err(PyretErrorKind::RaiseRuntime(Box::from("roughnum overflow"))),
-                }
-            }
-            _ => unreachable!(),
-        }
-    }
-}
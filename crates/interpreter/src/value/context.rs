@@ -1,18 +1,26 @@
 use std::{
-    cell::{Ref, RefCell},
-    ops::Deref,
+    cell::RefCell,
+    iter,
+    ops::{Deref, Range},
     rc::Rc,
     sync::Arc,
 };
 
 use pyret_error::PyretErrorKind;
-use pyret_lexer::{ast::IdentifierExpression, Token};
+use pyret_lexer::{
+    ast::{AnnotationType, IdentifierAnnotation, IdentifierExpression, TypeAnnotation},
+    Token,
+};
 
 use super::{PyretValueKind, TypePredicate};
 use crate::{
     io::Io,
     trove,
-    value::{function::FunctionSignature, PyretFunction, PyretValue, PyretValueScoped},
+    value::{
+        contract::{Blame, Wrapper},
+        function::{Evaluate, FunctionBody, FunctionSignature},
+        PyretFunction, PyretObject, PyretValue, PyretValueScoped,
+    },
     PyretResult,
 };
 
@@ -54,30 +62,31 @@ impl RegisteredDeclaration {
 #[derive(Clone)]
 pub enum Declaration {
     Value(Option<PyretValueScoped>),
+    Variable(Variable),
     Type(TypePredicate),
 }
 
+/// A `var` binding, whose value is stored in a cell so that it can be
+/// reassigned with `:=`.
+#[derive(Clone)]
+pub struct Variable {
+    pub cell: Rc<RefCell<PyretValue>>,
+    pub annotation: Option<TypeAnnotation>,
+}
+
 #[derive(Default)]
 pub struct Register {
     pub io: Io,
     pub declarations: Vec<RegisteredDeclaration>,
-    depth_of_scope: Option<usize>,
+    parent: Option<Context>,
 }
 
 impl Register {
     fn get_declaration(&mut self, name: &str) -> Option<&mut Declaration> {
         self.declarations
             .iter_mut()
-            .enumerate()
-            .rfind(|(depth, decl)| {
-                name == &*decl.name
-                    && match self.depth_of_scope {
-                        Some(depth_of_scope) if depth_of_scope > *depth => true,
-                        None => true,
-                        _ => false,
-                    }
-            })
-            .map(|(_, registered)| &mut registered.declaration)
+            .rfind(|decl| name == &*decl.name)
+            .map(|registered| &mut registered.declaration)
     }
 }
 
@@ -85,6 +94,54 @@ impl Register {
 pub struct Context(Rc<RefCell<Register>>);
 
 impl Context {
+    /// Creates a new scope, in which declarations are registered separately
+    /// from, but can still see the declarations of, this context.
+    #[must_use]
+    pub fn child(&self) -> Self {
+        let io = self.borrow().io.clone();
+
+        Self(Rc::new(RefCell::new(Register {
+            io,
+            declarations: Vec::new(),
+            parent: Some(self.clone()),
+        })))
+    }
+
+    /// Finds a declaration in this context or any of its parents.
+    fn find_declaration(&self, name: &str) -> Option<Declaration> {
+        let mut register = self.borrow_mut();
+
+        if let Some(declaration) = register.get_declaration(name) {
+            Some(declaration.clone())
+        } else {
+            register
+                .parent
+                .as_ref()
+                .and_then(|parent| parent.find_declaration(name))
+        }
+    }
+
+    /// Lists the declarations of this context and its parents, starting with
+    /// those of the outermost scope.
+    pub(crate) fn declarations(&self) -> Vec<(Box<str>, Declaration)> {
+        let register = self.borrow();
+
+        let mut declarations = register
+            .parent
+            .as_ref()
+            .map(Self::declarations)
+            .unwrap_or_default();
+
+        declarations.extend(
+            register
+                .declarations
+                .iter()
+                .map(|registered| (registered.name.clone(), registered.declaration.clone())),
+        );
+
+        declarations
+    }
+
     pub fn register_builtin_expr(&self, name: &'static str, value: PyretValue) {
         self.borrow_mut()
             .declarations
@@ -95,38 +152,57 @@ impl Context {
             ));
     }
 
+    /// Registers a local value, or initializes one that was declared without
+    /// a value, like the name of a recursive `rec` binding. A value declared
+    /// with `shadow` may shadow builtins and the declarations of parent
+    /// scopes.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the name is already declared in
+    /// this scope, or if it shadows a declaration of a parent scope.
     pub fn register_local_expr(
         &self,
         name: Box<str>,
         value: Option<PyretValue>,
+        span: Range<usize>,
         scope_level: usize,
-    ) {
+        shadows: bool,
+    ) -> PyretResult<()> {
         if let Some(declaration) = self.borrow_mut().get_declaration(&name) {
-            if let Declaration::Value(shadowing) = &declaration {
-                if let Some(shadowing) = shadowing {
-                    if shadowing.is_builtin {
-                        todo!(
-                            "The declaration of {name} shadows a built-in declaration of the same name."
-                        );
-                    } else {
-                        todo!(
-                            "This declaration of a name conflicts with an earlier declaration of the same name:"
-                        );
-                    }
-                } else if let Some(value) = value {
-                    *declaration = Declaration::Value(Some(PyretValueScoped::new_local(value)));
+            match declaration {
+                Declaration::Value(Some(shadowing)) if shadowing.is_builtin && shadows => {}
+                Declaration::Value(Some(shadowing)) if shadowing.is_builtin => {
+                    return Err(PyretErrorKind::ShadowsBuiltin {
+                        ident: name,
+                        span: span.into(),
+                    });
+                }
+                Declaration::Value(None) if value.is_some() => {
+                    *declaration = Declaration::Value(value.map(PyretValueScoped::new_local));
 
-                    return;
-                } else {
-                    todo!(
-                        "Contracts for functions can only be defined once, and the contract for {name} is already defined: "
-                    );
+                    return Ok(());
+                }
+                Declaration::Type(..) => {
+                    return Err(PyretErrorKind::NotAValue {
+                        ident: name,
+                        span: span.into(),
+                    });
+                }
+                declaration => {
+                    return Err(PyretErrorKind::DuplicateDeclaration {
+                        ident: name,
+                        span: span.into(),
+                        previous: declaration_span(declaration).map(Into::into),
+                    });
                 }
-            } else {
-                todo!("The declaration of {name} is not a value.");
             }
         }
 
+        if !shadows {
+            self.check_shadowing(&name, span)?;
+        }
+
         let declaration = RegisteredDeclaration::new_value(
             name,
             value.map(PyretValueScoped::new_local),
@@ -134,6 +210,78 @@ impl Context {
         );
 
         self.borrow_mut().declarations.push(declaration);
+
+        Ok(())
+    }
+
+    /// Registers a `var` binding, which is checked against its annotation
+    /// whenever it is reassigned, and which may shadow other declarations
+    /// like [`Context::register_local_expr`] when declared with `shadow`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the name is already declared in
+    /// this scope, or if it shadows a declaration of a parent scope.
+    pub fn register_local_variable(
+        &self,
+        name: Box<str>,
+        value: PyretValue,
+        annotation: Option<TypeAnnotation>,
+        scope_level: usize,
+        shadows: bool,
+    ) -> PyretResult<()> {
+        let span = value.span.clone().unwrap_or_default();
+
+        if let Some(declaration) = self.borrow_mut().get_declaration(&name) {
+            let is_builtin =
+                matches!(declaration, Declaration::Value(Some(scoped)) if scoped.is_builtin);
+
+            if !(is_builtin && shadows) {
+                return Err(PyretErrorKind::DuplicateDeclaration {
+                    ident: name,
+                    span: span.into(),
+                    previous: declaration_span(declaration).map(Into::into),
+                });
+            }
+        }
+
+        if !shadows {
+            self.check_shadowing(&name, span)?;
+        }
+
+        self.borrow_mut().declarations.push(RegisteredDeclaration {
+            name,
+            declaration: Declaration::Variable(Variable {
+                cell: Rc::new(RefCell::new(value)),
+                annotation,
+            }),
+            scope_level,
+        });
+
+        Ok(())
+    }
+
+    /// Checks that a new local declaration does not shadow a declaration in
+    /// any of the parents of this context.
+    fn check_shadowing(&self, name: &str, span: Range<usize>) -> PyretResult<()> {
+        let parent = self.borrow().parent.clone();
+
+        match parent.and_then(|parent| parent.find_declaration(name)) {
+            Some(Declaration::Value(Some(shadowing))) if shadowing.is_builtin => {
+                Err(PyretErrorKind::ShadowsBuiltin {
+                    ident: Box::from(name),
+                    span: span.into(),
+                })
+            }
+            Some(declaration @ (Declaration::Value(Some(..)) | Declaration::Variable(..))) => {
+                Err(PyretErrorKind::ShadowedDeclaration {
+                    ident: Box::from(name),
+                    span: span.into(),
+                    previous: declaration_span(&declaration).map(Into::into),
+                })
+            }
+            _ => Ok(()),
+        }
     }
 
     pub fn register_builtin_function<const N: usize>(
@@ -201,15 +349,10 @@ impl Context {
     ) -> PyretResult<()> {
         let param_types = param_types
             .iter()
-            .map(|name| {
-                self.get_type(name)?
-                    .ok_or_else(|| todo!("The type {name} has not been previously defined."))
-            })
+            .map(|name| self.get_bound_type(name))
             .collect::<PyretResult<_>>()?;
 
-        let return_type = self
-            .get_type(return_type)?
-            .ok_or_else(|| todo!("The type {return_type} has not been previously defined."))?;
+        let return_type = self.get_bound_type(return_type)?;
 
         self.borrow_mut()
             .declarations
@@ -247,20 +390,32 @@ impl Context {
         Ok(predicate)
     }
 
+    /// Registers a local type, like the type of a `data` declaration or a
+    /// generic type parameter.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the name is already declared in
+    /// this scope.
     pub fn register_local_type(
         &self,
         name: Box<str>,
         predicate: TypePredicate,
+        span: Range<usize>,
         scope_level: usize,
-    ) {
+    ) -> PyretResult<()> {
         if let Some(declaration) = self.borrow_mut().get_declaration(&name) {
-            if let Declaration::Type(_predicate) = &declaration {
-                todo!(
-                    "This declaration of a name conflicts with an earlier declaration of the same name:"
-                );
-            } else {
-                todo!("The declaration of {name} is not a type.");
-            }
+            return Err(match declaration {
+                Declaration::Type(..) => PyretErrorKind::DuplicateDeclaration {
+                    ident: name,
+                    span: span.into(),
+                    previous: None,
+                },
+                _ => PyretErrorKind::NotAType {
+                    ident: name,
+                    span: Some(span.into()),
+                },
+            });
         }
 
         self.borrow_mut()
@@ -270,95 +425,383 @@ impl Context {
                 predicate,
                 scope_level,
             ));
+
+        Ok(())
     }
 
-    pub fn get_value(&self, name: &str) -> PyretResult<PyretValueScoped> {
-        if let Some(declaration) = self.borrow_mut().get_declaration(name) {
-            if let Declaration::Value(value) = declaration {
-                if let Some(value_scoped) = value {
-                    Ok(value_scoped.clone())
-                } else {
-                    Err(todo!(
-                        "The identifier is unbound. Although it has been previously defined, it is being used before it has been is initialized to a value: {name}"
-                    ))
-                }
-            } else {
-                Err(todo!("The declaration of {name} is not a value."))
+    /// Looks up the value of an identifier.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the name is unbound, has not been
+    /// initialized yet, or names a type.
+    pub fn get_value(&self, ident: &IdentifierExpression) -> PyretResult<PyretValueScoped> {
+        match self.find_declaration(&ident.name) {
+            Some(Declaration::Value(Some(value_scoped))) => Ok(value_scoped),
+            Some(Declaration::Value(None)) => Err(PyretErrorKind::UninitializedIdentifier {
+                ident: ident.name.clone(),
+                span: ident.span().into(),
+            }),
+            Some(Declaration::Variable(variable)) => {
+                Ok(PyretValueScoped::new_local(variable.cell.borrow().clone()))
             }
-        } else {
-            Err(todo!(
-                "The identifier is unbound. It has not been previously defined: {name}"
-            ))
+            Some(Declaration::Type(..)) => Err(PyretErrorKind::NotAValue {
+                ident: ident.name.clone(),
+                span: ident.span().into(),
+            }),
+            None => Err(PyretErrorKind::UnboundIdentifier {
+                ident: ident.name.clone(),
+                span: ident.span().into(),
+            }),
         }
     }
 
-    pub fn get_type(&self, name: &str) -> PyretResult<Option<TypePredicate>> {
-        self.borrow_mut()
-            .get_declaration(name)
+    /// Finds the `var` binding that an assignment to `ident` updates.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the name is unbound, or if it is
+    /// not bound with `var`.
+    pub fn get_variable(&self, ident: &IdentifierExpression) -> PyretResult<Variable> {
+        match self.find_declaration(&ident.name) {
+            Some(Declaration::Variable(variable)) => Ok(variable),
+            Some(..) => Err(PyretErrorKind::AssignToNonVariable {
+                ident: ident.name.clone(),
+                span: ident.span().into(),
+            }),
+            None => Err(PyretErrorKind::UnboundIdentifier {
+                ident: ident.name.clone(),
+                span: ident.span().into(),
+            }),
+        }
+    }
+
+    /// Looks up a type by its name, where the span points at its use.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the name does not name a type.
+    pub fn get_type(
+        &self,
+        name: &str,
+        span: Option<Range<usize>>,
+    ) -> PyretResult<Option<TypePredicate>> {
+        self.find_declaration(name)
             .map(|declaration| {
                 if let Declaration::Type(predicate) = declaration {
-                    Ok(Arc::clone(predicate))
+                    Ok(predicate)
                 } else {
-                    Err(todo!("The declaration of {name} is not a type."))
+                    Err(PyretErrorKind::NotAType {
+                        ident: Box::from(name),
+                        span: span.clone().map(Into::into),
+                    })
                 }
             })
             .transpose()
     }
 
-    pub fn call_function(
+    /// Looks up a type by its name, which has to be bound.
+    fn get_bound_type(&self, name: &str) -> PyretResult<TypePredicate> {
+        self.get_type(name, None)?
+            .ok_or_else(|| PyretErrorKind::UnboundType {
+                ident: Box::from(name),
+                span: None,
+            })
+    }
+
+    /// Checks whether a value satisfies an annotation, resolving the names
+    /// of types in this context.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if a name in the annotation does not
+    /// name a type, or if a predicate raises an error.
+    pub fn check_annotation(
         &self,
-        ident: IdentifierExpression,
-        args: Vec<PyretValue>,
-        scope_level: usize,
+        annotation: &AnnotationType,
+        value: PyretValue,
+        evaluator: &mut dyn Evaluate,
+    ) -> PyretResult<bool> {
+        let blame = Blame::new("the value", 0..0, 0..0);
+
+        match self.check_contract(annotation, value, &blame, evaluator) {
+            Ok(..) => Ok(true),
+            Err(PyretErrorKind::ContractViolation { .. }) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Checks a value against an annotation, resolving the names of types in
+    /// this context, and results in the value, where functions checked
+    /// against arrow annotations are wrapped to check their arguments and
+    /// results whenever they are applied.
+    ///
+    /// # Errors
+    ///
+    /// Will return a [`PyretErrorKind::ContractViolation`] naming the part of
+    /// the value that does not satisfy the annotation, or another
+    /// [`PyretErrorKind`] if the annotation could not be checked.
+    pub fn check_contract(
+        &self,
+        annotation: &AnnotationType,
+        value: PyretValue,
+        blame: &Blame,
+        evaluator: &mut dyn Evaluate,
     ) -> PyretResult<PyretValue> {
-        let Some(depth) = self
-            .borrow()
-            .declarations
-            .iter()
-            .position(|decl| decl.name == ident.name)
-        else {
-            todo!(
-                "The identifier is unbound. It has not been previously defined: {}",
-                ident.name
-            )
-        };
+        match annotation {
+            AnnotationType::NameAnnotation {
+                name,
+                parameters,
+                predicate,
+            } => {
+                let ident = match name {
+                    IdentifierAnnotation::Name(ident) => ident,
+                    IdentifierAnnotation::Dot(names) => {
+                        let name = names
+                            .iter()
+                            .map(|ident| &*ident.name)
+                            .collect::<Vec<_>>()
+                            .join(".");
+
+                        let span = names.first().map(Token::start).unwrap_or_default()
+                            ..names.last().map(Token::end).unwrap_or_default();
+
+                        // Modules do not provide types yet, so a type named
+                        // through one is never bound.
+                        return Err(PyretErrorKind::UnboundType {
+                            ident: name.into_boxed_str(),
+                            span: Some(span.into()),
+                        });
+                    }
+                };
 
-        self.borrow_mut().depth_of_scope = Some(depth);
+                let Some(r#type) = self.get_type(&ident.name, Some(ident.span()))? else {
+                    return Err(PyretErrorKind::UnboundType {
+                        ident: ident.name.clone(),
+                        span: Some(ident.span().into()),
+                    });
+                };
+
+                if !r#type(value.clone(), self.clone()) {
+                    return Err(blame.violation(&value));
+                }
 
-        let function = {
-            let binding = self.borrow();
+                if !parameters.is_empty() {
+                    self.check_parameters(ident, parameters, &value, blame, evaluator)?;
+                }
 
-            if let Declaration::Value(declaration) = &binding.declarations[depth].declaration {
-                if let Some(declaration) = &declaration {
-                    if let PyretValueKind::Function(function) = &*declaration.value.kind {
-                        Ok(function.clone())
-                    } else {
-                        Err(PyretErrorKind::InvalidFunctionApplication {
-                            span: ident.span().into(),
-                        })
+                match predicate {
+                    Some(predicate)
+                        if !self.check_predicate(predicate, value.clone(), evaluator)? =>
+                    {
+                        Err(blame.violation(&value))
+                    }
+                    _ => Ok(value),
+                }
+            }
+            AnnotationType::TupleAnnotation(annotations) => {
+                let PyretValueKind::Tuple(elements) = &*value.kind else {
+                    return Err(blame.violation(&value));
+                };
+
+                if elements.len() != annotations.len() {
+                    return Err(blame.violation(&value));
+                }
+
+                let elements = annotations
+                    .iter()
+                    .zip(elements.iter())
+                    .enumerate()
+                    .map(|(index, (annotation, element))| {
+                        let blame = blame.part(format_args!("element {}", index + 1));
+
+                        self.check_contract(annotation, element.clone(), &blame, evaluator)
+                    })
+                    .collect::<PyretResult<_>>()?;
+
+                Ok(PyretValue {
+                    span: value.span,
+                    kind: Rc::new(PyretValueKind::Tuple(elements)),
+                })
+            }
+            AnnotationType::RecordAnnotation(fields) => {
+                let mut checked = match &*value.kind {
+                    PyretValueKind::Object(object) => Some(object.fields.clone()),
+                    PyretValueKind::Data(..) => None,
+                    _ => return Err(blame.violation(&value)),
+                };
+
+                for (name, annotation) in fields {
+                    let field = match &*value.kind {
+                        PyretValueKind::Object(object) => object.get(name),
+                        PyretValueKind::Data(data) => data.get(name),
+                        _ => None,
+                    };
+
+                    let Some(field) = field else {
+                        return Err(blame.violation(&value));
+                    };
+
+                    let blame = blame.part(format_args!("field {name}"));
+                    let field =
+                        self.check_contract(annotation, field.clone(), &blame, evaluator)?;
+
+                    // The fields of data instances are only checked, as their
+                    // variants could not be rebuilt from wrapped fields.
+                    if let Some(checked) = &mut checked {
+                        for (key, value) in checked.iter_mut() {
+                            if key == name {
+                                *value = field.clone();
+                            }
+                        }
                     }
-                } else {
-                    Err(todo!(
-                        "The identifier is unbound. Although it has been previously defined, it is being used before it has been is initialized to a value: {}",
-                        ident.name
-                    ))
                 }
-            } else {
-                Err(todo!("The declaration of {} is not a value.", ident.name))
-            }?
+
+                Ok(match checked {
+                    Some(fields) => PyretValue {
+                        span: value.span,
+                        kind: Rc::new(PyretValueKind::Object(PyretObject::new(fields))),
+                    },
+                    None => value,
+                })
+            }
+            AnnotationType::ArrowAnnotation {
+                arguments,
+                return_annotation,
+                ..
+            } => match &*value.kind {
+                PyretValueKind::Function(function)
+                    if function.param_types.len() == arguments.len() =>
+                {
+                    let function =
+                        self.wrap_function(function, arguments, return_annotation, blame);
+
+                    Ok(PyretValue {
+                        span: value.span,
+                        kind: Rc::new(PyretValueKind::Function(function)),
+                    })
+                }
+                _ => Err(blame.violation(&value)),
+            },
+        }
+    }
+
+    /// Checks the fields of an instance of a parametric `data` declaration
+    /// against the parameters of an annotation like `List<Number>`, by
+    /// substituting them for the type parameters in the annotations of the
+    /// fields, so that every element of a list is checked.
+    ///
+    /// The type named `ident` has to take as many parameters as the
+    /// annotation gives it.
+    fn check_parameters(
+        &self,
+        ident: &IdentifierExpression,
+        parameters: &[AnnotationType],
+        value: &PyretValue,
+        blame: &Blame,
+        evaluator: &mut dyn Evaluate,
+    ) -> PyretResult<()> {
+        let declared = match &*value.kind {
+            PyretValueKind::Data(data) => data.parameters.as_deref(),
+            _ => None,
+        };
+
+        let expected = declared.map_or(0, |declared| declared.names.len());
+
+        if expected != parameters.len() {
+            return Err(PyretErrorKind::TypeArityMismatch {
+                ident: ident.name.clone(),
+                expected,
+                found: parameters.len(),
+                span: blame.annotation.clone().into(),
+            });
+        }
+
+        let (PyretValueKind::Data(data), Some(declared)) = (&*value.kind, declared) else {
+            return Ok(());
+        };
+
+        let Some(fields) = &data.fields else {
+            return Ok(());
         };
 
-        let result = function.call(args, scope_level)?;
+        for ((name, field), annotation) in fields.iter().zip(declared.fields.iter()) {
+            if let Some(annotation) = annotation {
+                let annotation = substitute(annotation, &declared.names, parameters);
+                let blame = blame.part(format_args!("field {name}"));
 
-        self.borrow_mut().depth_of_scope = None;
+                self.check_contract(&annotation, field.clone(), &blame, evaluator)?;
+            }
+        }
 
-        Ok(result)
+        Ok(())
     }
 
-    pub fn pop_scope(&self, scope_level: usize) {
-        self.borrow_mut()
-            .declarations
-            .retain(|registered| registered.scope_level <= scope_level);
+    /// Wraps a function that satisfies the arity of an arrow annotation, so
+    /// that its arguments and result are checked against the annotation
+    /// whenever it is applied.
+    fn wrap_function(
+        &self,
+        function: &PyretFunction,
+        arguments: &[AnnotationType],
+        return_annotation: &AnnotationType,
+        blame: &Blame,
+    ) -> PyretFunction {
+        let wrapper = Wrapper {
+            function: function.clone(),
+            arguments: arguments.into(),
+            return_annotation: return_annotation.clone(),
+            blame: blame.clone(),
+        };
+
+        PyretFunction::with_body(
+            function.name.clone(),
+            Box::from_iter([]),
+            iter::repeat_with(trove::global::Any::predicate)
+                .take(function.param_types.len())
+                .collect(),
+            trove::global::Any::predicate(),
+            FunctionBody::Wrapped(Rc::new(wrapper)),
+            self.clone(),
+        )
+    }
+
+    /// Applies the predicate of a refined annotation like `Number%(is-even)`
+    /// to a value, which has to result in a Boolean.
+    fn check_predicate(
+        &self,
+        predicate: &IdentifierExpression,
+        value: PyretValue,
+        evaluator: &mut dyn Evaluate,
+    ) -> PyretResult<bool> {
+        let function = self.get_value(predicate)?.value;
+
+        let result = self.call_function(&function, predicate.span(), vec![value], 0, evaluator)?;
+
+        match &*result.kind {
+            PyretValueKind::Boolean(satisfied) => Ok(*satisfied),
+            _ => Err(PyretErrorKind::NonBooleanPredicate {
+                value: result.to_string().into_boxed_str(),
+                predicate: predicate.span().into(),
+            }),
+        }
+    }
+
+    /// Applies an evaluated function to the arguments, where the span points
+    /// at the expression that evaluated to it.
+    pub fn call_function(
+        &self,
+        function: &PyretValue,
+        span: Range<usize>,
+        args: Vec<PyretValue>,
+        scope_level: usize,
+        evaluator: &mut dyn Evaluate,
+    ) -> PyretResult<PyretValue> {
+        let PyretValueKind::Function(function) = &*function.kind else {
+            return Err(PyretErrorKind::InvalidFunctionApplication { span: span.into() });
+        };
+
+        function.call(args, span, scope_level, evaluator)
     }
 }
 
@@ -369,3 +812,67 @@ impl Deref for Context {
         &self.0
     }
 }
+
+/// Replaces the type parameters named `names` in an annotation with the
+/// corresponding annotations of `parameters`.
+fn substitute(
+    annotation: &AnnotationType,
+    names: &[Box<str>],
+    parameters: &[AnnotationType],
+) -> AnnotationType {
+    let substitute_all = |annotations: &[AnnotationType]| {
+        annotations
+            .iter()
+            .map(|annotation| substitute(annotation, names, parameters))
+            .collect()
+    };
+
+    match annotation {
+        AnnotationType::NameAnnotation {
+            name: IdentifierAnnotation::Name(ident),
+            parameters: own_parameters,
+            predicate: None,
+        } if own_parameters.is_empty() => names
+            .iter()
+            .position(|name| *name == ident.name)
+            .and_then(|index| parameters.get(index))
+            .unwrap_or(annotation)
+            .clone(),
+        AnnotationType::NameAnnotation {
+            name,
+            parameters: own_parameters,
+            predicate,
+        } => AnnotationType::NameAnnotation {
+            name: name.clone(),
+            parameters: substitute_all(own_parameters),
+            predicate: predicate.clone(),
+        },
+        AnnotationType::ArrowAnnotation {
+            generics,
+            arguments,
+            return_annotation,
+        } => AnnotationType::ArrowAnnotation {
+            generics: generics.clone(),
+            arguments: substitute_all(arguments),
+            return_annotation: Box::new(substitute(return_annotation, names, parameters)),
+        },
+        AnnotationType::TupleAnnotation(annotations) => {
+            AnnotationType::TupleAnnotation(substitute_all(annotations))
+        }
+        AnnotationType::RecordAnnotation(fields) => AnnotationType::RecordAnnotation(
+            fields
+                .iter()
+                .map(|(name, annotation)| (name.clone(), substitute(annotation, names, parameters)))
+                .collect(),
+        ),
+    }
+}
+
+/// Finds where a declaration was made, from the span of its value.
+pub(crate) fn declaration_span(declaration: &Declaration) -> Option<Range<usize>> {
+    match declaration {
+        Declaration::Value(Some(scoped)) => scoped.value.span.clone(),
+        Declaration::Variable(variable) => variable.cell.borrow().span.clone(),
+        Declaration::Value(None) | Declaration::Type(..) => None,
+    }
+}
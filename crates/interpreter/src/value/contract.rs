@@ -0,0 +1,114 @@
+use std::{fmt, ops::Range};
+
+use pyret_error::{PyretErrorKind, PyretResult};
+use pyret_lexer::ast::{AnnotationType, TypeAnnotation};
+
+use super::{function::Evaluate, PyretFunction, PyretValue};
+use crate::Context;
+
+/// The annotations of the parameters and the result of a function, which are
+/// checked with blame whenever the function is applied.
+pub struct Contracts {
+    pub params: Box<[Option<TypeAnnotation>]>,
+    pub result: Option<TypeAnnotation>,
+}
+
+/// Names the part of a program that is at fault when a value does not
+/// satisfy an annotation, like `field x of argument 1 of f`.
+#[derive(Clone)]
+pub struct Blame {
+    pub party: Box<str>,
+    /// The span of the annotation that the value is checked against.
+    pub annotation: Range<usize>,
+    /// The span pointed at when the value does not have one.
+    pub fallback: Range<usize>,
+}
+
+impl Blame {
+    #[must_use]
+    pub fn new(
+        party: impl Into<Box<str>>,
+        annotation: Range<usize>,
+        fallback: Range<usize>,
+    ) -> Self {
+        Self {
+            party: party.into(),
+            annotation,
+            fallback,
+        }
+    }
+
+    /// Blames a part of the value, like one of its fields.
+    #[must_use]
+    pub fn part(&self, part: impl fmt::Display) -> Self {
+        Self {
+            party: format!("{part} of {}", self.party).into_boxed_str(),
+            annotation: self.annotation.clone(),
+            fallback: self.fallback.clone(),
+        }
+    }
+
+    /// Creates the error raised when the value does not satisfy the
+    /// annotation.
+    #[must_use]
+    pub fn violation(&self, value: &PyretValue) -> PyretErrorKind {
+        PyretErrorKind::ContractViolation {
+            blame: self.party.clone(),
+            value: value.to_string().into_boxed_str(),
+            span: value
+                .span
+                .clone()
+                .unwrap_or_else(|| self.fallback.clone())
+                .into(),
+            annotation: self.annotation.clone().into(),
+        }
+    }
+}
+
+/// A function checked against an arrow annotation, whose arguments and result
+/// are checked whenever it is applied.
+pub struct Wrapper {
+    pub function: PyretFunction,
+    pub arguments: Box<[AnnotationType]>,
+    pub return_annotation: AnnotationType,
+    pub blame: Blame,
+}
+
+impl Wrapper {
+    /// Applies the wrapped function, checking the arguments and the result
+    /// in the context.
+    ///
+    /// # Errors
+    ///
+    /// Will return a [`PyretErrorKind::ContractViolation`] if an argument or
+    /// the result does not satisfy the annotation, or another
+    /// [`PyretErrorKind`] if the function raises an error.
+    pub fn apply(
+        &self,
+        args: Vec<PyretValue>,
+        context: &Context,
+        evaluator: &mut dyn Evaluate,
+    ) -> PyretResult<PyretValue> {
+        let args = args
+            .into_iter()
+            .zip(self.arguments.iter())
+            .enumerate()
+            .map(|(index, (arg, annotation))| {
+                let blame = self.blame.part(format_args!("argument {}", index + 1));
+
+                context.check_contract(annotation, arg, &blame, evaluator)
+            })
+            .collect::<PyretResult<Vec<_>>>()?;
+
+        let value = self
+            .function
+            .call(args, self.blame.fallback.clone(), 0, evaluator)?;
+
+        context.check_contract(
+            &self.return_annotation,
+            value,
+            &self.blame.part("the result"),
+            evaluator,
+        )
+    }
+}
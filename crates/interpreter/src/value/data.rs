@@ -0,0 +1,96 @@
+use std::{fmt, rc::Rc};
+
+use pyret_lexer::ast::AnnotationType;
+
+use super::{Fields, PyretValue};
+
+/// The type parameters of a parametric `data` declaration like
+/// `data Pair<a, b>`, along with the annotations of the fields of a variant,
+/// which are checked against annotations like `Pair<Number, String>`.
+pub struct TypeParameters {
+    pub names: Box<[Box<str>]>,
+    pub fields: Box<[Option<AnnotationType>]>,
+}
+
+/// An instance of a variant of a `data` declaration.
+#[derive(Clone)]
+pub struct PyretData {
+    pub type_name: Box<str>,
+    pub variant: Box<str>,
+    /// The names of all the variants of the declaration.
+    pub variants: Rc<[Box<str>]>,
+    /// The fields of the variant, or [`None`] if it is a singleton variant.
+    pub fields: Option<Fields>,
+    /// The members of the `with:` and `sharing:` sections of the declaration.
+    pub members: Rc<Fields>,
+    /// The type parameters of the declaration, or [`None`] if it has none.
+    pub parameters: Option<Rc<TypeParameters>>,
+}
+
+impl PyretData {
+    #[must_use]
+    pub const fn new(
+        type_name: Box<str>,
+        variant: Box<str>,
+        variants: Rc<[Box<str>]>,
+        fields: Option<Fields>,
+        members: Rc<Fields>,
+        parameters: Option<Rc<TypeParameters>>,
+    ) -> Self {
+        Self {
+            type_name,
+            variant,
+            variants,
+            fields,
+            members,
+            parameters,
+        }
+    }
+
+    /// Looks up the value of a field or member by its name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&PyretValue> {
+        self.fields
+            .iter()
+            .flat_map(|fields| fields.iter())
+            .chain(self.members.iter())
+            .find_map(|(key, value)| (&**key == name).then_some(value))
+    }
+}
+
+impl PartialEq for PyretData {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_name == other.type_name
+            && self.variant == other.variant
+            && match (&self.fields, &other.fields) {
+                (Some(left_fields), Some(right_fields)) => left_fields
+                    .iter()
+                    .zip(right_fields.iter())
+                    .all(|((_, left), (_, right))| left.kind == right.kind),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl fmt::Display for PyretData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.variant)?;
+
+        if let Some(fields) = &self.fields {
+            write!(f, "(")?;
+
+            for (index, (_, value)) in fields.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write!(f, "{value}")?;
+            }
+
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
+}
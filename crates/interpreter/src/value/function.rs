@@ -1,19 +1,90 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{iter, ops::Range, rc::Rc};
 
-use super::TypePredicate;
-use crate::{trove, Context, PyretResult, PyretValue, Register};
+use pyret_error::PyretErrorKind;
+use pyret_lexer::{ast, Token};
+
+use super::{
+    contract::{Blame, Contracts, Wrapper},
+    TypePredicate,
+};
+use crate::{trove, Context, PyretResult, PyretValue};
 
 pub type FunctionSignature =
     Rc<dyn Fn(&mut dyn Iterator<Item = PyretValue>, Context) -> PyretResult<PyretValue>>;
 
+/// Evaluates the bodies of functions declared in a program, on the
+/// interpreter running it.
+pub trait Evaluate {
+    /// Evaluates the body of a function with its parameters bound to the
+    /// arguments in the context, where the span points at the application.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the body raises an error.
+    fn evaluate(
+        &mut self,
+        body: &PyretBody,
+        args: Vec<PyretValue>,
+        span: Range<usize>,
+        context: Context,
+    ) -> PyretResult<PyretValue>;
+}
+
+/// The parameters and the statements of a function declared in a program.
+pub struct PyretBody {
+    pub params: Vec<ast::Parameter>,
+    pub block: Vec<ast::Statement>,
+    /// The file the function was declared in, which its errors are reported
+    /// in.
+    pub file_id: usize,
+}
+
+#[derive(Clone)]
+pub enum FunctionBody {
+    /// A function implemented in Rust, like those of the trove.
+    Native(FunctionSignature),
+    /// A function declared in a program, which is evaluated by the
+    /// interpreter applying it.
+    Pyret(Rc<PyretBody>),
+    /// A method bound to its receiver, which is passed as the first argument.
+    Bound(Rc<PyretFunction>, PyretValue),
+    /// A function checked against an arrow annotation.
+    Wrapped(Rc<Wrapper>),
+}
+
+impl FunctionBody {
+    fn apply(
+        &self,
+        args: Vec<PyretValue>,
+        span: Range<usize>,
+        context: Context,
+        evaluator: &mut dyn Evaluate,
+    ) -> PyretResult<PyretValue> {
+        match self {
+            Self::Native(body) => body(&mut args.into_iter(), context),
+            Self::Pyret(body) => evaluator.evaluate(body, args, span, context),
+            Self::Bound(method, receiver) => {
+                let span = receiver.span.clone().unwrap_or_default();
+                let args = iter::once(receiver.clone()).chain(args).collect();
+
+                method.call(args, span, 0, evaluator)
+            }
+            Self::Wrapped(wrapper) => wrapper.apply(args, &context, evaluator),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct PyretFunction {
     pub name: Box<str>,
     pub generic_types: Box<[Box<str>]>,
     pub param_types: Box<[TypePredicate]>,
     pub return_type: TypePredicate,
-    pub body: FunctionSignature,
+    pub body: FunctionBody,
     pub context: Context,
+    /// The annotations that the arguments and the result are checked against
+    /// with blame, instead of the predicates of the types.
+    pub contracts: Option<Rc<Contracts>>,
 }
 
 impl PyretFunction {
@@ -25,6 +96,25 @@ impl PyretFunction {
         return_type: TypePredicate,
         body: FunctionSignature,
         context: Context,
+    ) -> Self {
+        Self::with_body(
+            name,
+            generic_types,
+            param_types,
+            return_type,
+            FunctionBody::Native(body),
+            context,
+        )
+    }
+
+    #[must_use]
+    pub fn with_body(
+        name: Box<str>,
+        generic_types: Box<[Box<str>]>,
+        param_types: Box<[TypePredicate]>,
+        return_type: TypePredicate,
+        body: FunctionBody,
+        context: Context,
     ) -> Self {
         Self {
             name,
@@ -33,35 +123,126 @@ impl PyretFunction {
             return_type,
             body,
             context,
+            contracts: None,
         }
     }
 
-    pub fn call(&self, args: Vec<PyretValue>, scope_level: usize) -> PyretResult<PyretValue> {
-        if args.len() == self.param_types.len() {
-            for generic in self.generic_types.iter() {
-                let any = trove::global::Any::predicate();
+    #[must_use]
+    pub fn with_contracts(mut self, contracts: Contracts) -> Self {
+        self.contracts = Some(Rc::new(contracts));
+        self
+    }
 
-                self.context
-                    .register_local_type(generic.clone(), any, scope_level);
-            }
+    /// Binds the first argument of a method to its receiver, creating a
+    /// function of the remaining arguments, which are checked when the method
+    /// is applied.
+    #[must_use]
+    pub fn bind(&self, receiver: PyretValue) -> Self {
+        Self::with_body(
+            self.name.clone(),
+            self.generic_types.clone(),
+            iter::repeat_with(trove::global::Any::predicate)
+                .take(self.param_types.len().saturating_sub(1))
+                .collect(),
+            trove::global::Any::predicate(),
+            FunctionBody::Bound(Rc::new(self.clone()), receiver),
+            self.context.clone(),
+        )
+    }
 
-            for (arg, predicate) in args.iter().zip(self.param_types.iter()) {
-                if !predicate(arg.clone(), self.context.clone()) {
-                    todo!("Incorrect argument type.")
-                }
-            }
+    /// Applies the function to the arguments, checking them and the result
+    /// against the annotations of the function, where the span points at the
+    /// application.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the number of arguments is wrong,
+    /// if an argument or the result does not satisfy its annotation, or if
+    /// the body raises an error.
+    pub fn call(
+        &self,
+        args: Vec<PyretValue>,
+        span: Range<usize>,
+        scope_level: usize,
+        evaluator: &mut dyn Evaluate,
+    ) -> PyretResult<PyretValue> {
+        if args.len() != self.param_types.len() {
+            return Err(PyretErrorKind::ArityMismatch {
+                function: self.name.clone(),
+                expected: self.param_types.len(),
+                found: args.len(),
+                span: span.into(),
+            });
+        }
 
-            let value = (self.body)(&mut args.into_iter(), self.context.clone());
+        let context = self.context.child();
 
-            if let Ok(value) = &value {
-                if !(self.return_type)(value.clone(), self.context.clone()) {
-                    todo!("Incorrect return type.")
+        for generic in self.generic_types.iter() {
+            let any = trove::global::Any::predicate();
+
+            context.register_local_type(generic.clone(), any, span.clone(), scope_level)?;
+        }
+
+        if let Some(contracts) = &self.contracts {
+            let args = args
+                .into_iter()
+                .zip(contracts.params.iter())
+                .enumerate()
+                .map(|(position, (arg, annotation))| match annotation {
+                    Some(annotation) => {
+                        let blame = Blame::new(
+                            format!("argument {} of {}", position + 1, self.name),
+                            annotation.span(),
+                            span.clone(),
+                        );
+
+                        context.check_contract(&annotation.value, arg, &blame, evaluator)
+                    }
+                    None => Ok(arg),
+                })
+                .collect::<PyretResult<Vec<_>>>()?;
+
+            let value = self
+                .body
+                .apply(args, span.clone(), context.clone(), evaluator)?;
+
+            return match &contracts.result {
+                Some(annotation) => {
+                    let blame = Blame::new(
+                        format!("the result of {}", self.name),
+                        annotation.span(),
+                        span,
+                    );
+
+                    context.check_contract(&annotation.value, value, &blame, evaluator)
                 }
+                None => Ok(value),
+            };
+        }
+
+        for (position, (arg, predicate)) in args.iter().zip(self.param_types.iter()).enumerate() {
+            if !predicate(arg.clone(), context.clone()) {
+                return Err(PyretErrorKind::ArgumentTypeMismatch {
+                    function: self.name.clone(),
+                    position: position + 1,
+                    value: arg.to_string().into_boxed_str(),
+                    span: arg.span.clone().unwrap_or_else(|| span.clone()).into(),
+                });
             }
+        }
+
+        let value = self
+            .body
+            .apply(args, span.clone(), context.clone(), evaluator)?;
 
-            value
-        } else {
-            todo!("Incorrect number of arguments.")
+        if !(self.return_type)(value.clone(), context) {
+            return Err(PyretErrorKind::ReturnTypeMismatch {
+                function: self.name.clone(),
+                value: value.to_string().into_boxed_str(),
+                span: span.into(),
+            });
         }
+
+        Ok(value)
     }
 }
@@ -1,13 +1,25 @@
 pub mod context;
+pub mod contract;
+pub mod data;
 pub mod function;
+pub mod object;
+pub mod reference;
 
-use std::{cell::RefCell, fmt, ops::Range, rc::Rc, sync::Arc};
+use std::{fmt, ops::Range, rc::Rc, sync::Arc};
 
+pub use data::PyretData;
 pub use function::PyretFunction;
+pub use object::PyretObject;
+use pyret_error::{PyretErrorKind, PyretResult};
+use pyret_lexer::{ast::IdentifierExpression, Token};
 use pyret_number::PyretNumber;
+pub use reference::PyretRef;
 
 use crate::Context;
 
+/// Named fields, in the order they were declared.
+pub type Fields = Box<[(Box<str>, PyretValue)]>;
+
 pub type TypePredicate = Arc<dyn Fn(PyretValue, Context) -> bool + Send + Sync>;
 
 #[derive(Clone)]
@@ -48,6 +60,71 @@ impl PyretValue {
             kind,
         }
     }
+
+    /// Looks up a field of an object or an instance of a data variant, where
+    /// `span` is the span of the expression this value was evaluated from.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if this value is not an object, if it
+    /// does not have the field, or if the field is a `ref` field.
+    pub fn get_field(
+        &self,
+        property: &IdentifierExpression,
+        span: Range<usize>,
+    ) -> PyretResult<Self> {
+        let field = self.lookup(property, span)?;
+
+        if let PyretValueKind::Ref(..) = &*field.kind {
+            return Err(PyretErrorKind::RefFieldLookup {
+                field: property.name.clone(),
+                property: property.span().into(),
+            });
+        }
+
+        Ok(field)
+    }
+
+    /// Looks up a `ref` field of an object or an instance of a data variant.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if this value is not an object, if it
+    /// does not have the field, or if the field is not a `ref` field.
+    pub fn get_ref(
+        &self,
+        property: &IdentifierExpression,
+        span: Range<usize>,
+    ) -> PyretResult<PyretRef> {
+        let field = self.lookup(property, span)?;
+
+        if let PyretValueKind::Ref(reference) = &*field.kind {
+            Ok(reference.clone())
+        } else {
+            Err(PyretErrorKind::NotARefField {
+                field: property.name.clone(),
+                property: property.span().into(),
+            })
+        }
+    }
+
+    fn lookup(&self, property: &IdentifierExpression, span: Range<usize>) -> PyretResult<Self> {
+        let field = match &*self.kind {
+            PyretValueKind::Object(object) => object.get(&property.name),
+            PyretValueKind::Data(data) => data.get(&property.name),
+            _ => {
+                return Err(PyretErrorKind::NotAnObject {
+                    value: self.to_string().into_boxed_str(),
+                    object: span.into(),
+                });
+            }
+        };
+
+        field.cloned().ok_or_else(|| PyretErrorKind::FieldNotFound {
+            field: property.name.clone(),
+            property: property.span().into(),
+        })
+    }
 }
 
 impl From<PyretValueKind> for PyretValue {
@@ -64,6 +141,11 @@ pub enum PyretValueKind {
     String(Box<str>),
     Boolean(bool),
     Function(PyretFunction),
+    Method(PyretFunction),
+    Data(PyretData),
+    Object(PyretObject),
+    Tuple(Box<[PyretValue]>),
+    Ref(PyretRef),
     Nothing,
 }
 
@@ -74,6 +156,23 @@ impl fmt::Display for PyretValue {
             PyretValueKind::String(string) => write!(f, "\"{}\"", string.escape_debug()),
             PyretValueKind::Boolean(boolean) => write!(f, "{boolean}"),
             PyretValueKind::Function(PyretFunction { name, .. }) => write!(f, "<function:{name}>"),
+            PyretValueKind::Method(PyretFunction { name, .. }) => write!(f, "<method:{name}>"),
+            PyretValueKind::Data(data) => write!(f, "{data}"),
+            PyretValueKind::Object(object) => write!(f, "{object}"),
+            PyretValueKind::Tuple(elements) => {
+                write!(f, "{{")?;
+
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, "; ")?;
+                    }
+
+                    write!(f, "{element}")?;
+                }
+
+                write!(f, "}}")
+            }
+            PyretValueKind::Ref(reference) => write!(f, "{reference}"),
             PyretValueKind::Nothing => Ok(()),
         }
     }
@@ -87,6 +186,16 @@ impl PartialEq for PyretValueKind {
             (Self::Boolean(left_boolean), Self::Boolean(right_boolean)) => {
                 left_boolean == right_boolean
             }
+            (Self::Data(left_data), Self::Data(right_data)) => left_data == right_data,
+            (Self::Object(left_object), Self::Object(right_object)) => left_object == right_object,
+            (Self::Tuple(left_elements), Self::Tuple(right_elements)) => {
+                left_elements.len() == right_elements.len()
+                    && left_elements
+                        .iter()
+                        .zip(right_elements.iter())
+                        .all(|(left, right)| left.kind == right.kind)
+            }
+            (Self::Ref(left_ref), Self::Ref(right_ref)) => left_ref == right_ref,
             (Self::Nothing, Self::Nothing) => true,
             _ => false,
         }
@@ -0,0 +1,50 @@
+use std::fmt;
+
+use super::{Fields, PyretValue};
+
+/// An object created from an object literal.
+#[derive(Clone)]
+pub struct PyretObject {
+    pub fields: Fields,
+}
+
+impl PyretObject {
+    #[must_use]
+    pub const fn new(fields: Fields) -> Self {
+        Self { fields }
+    }
+
+    /// Looks up the value of a field by its name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&PyretValue> {
+        self.fields
+            .iter()
+            .find_map(|(key, value)| (&**key == name).then_some(value))
+    }
+}
+
+impl PartialEq for PyretObject {
+    fn eq(&self, other: &Self) -> bool {
+        self.fields.len() == other.fields.len()
+            && self
+                .fields
+                .iter()
+                .all(|(key, left)| other.get(key).is_some_and(|right| left.kind == right.kind))
+    }
+}
+
+impl fmt::Display for PyretObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+
+        for (index, (key, value)) in self.fields.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{key}: {value}")?;
+        }
+
+        write!(f, "}}")
+    }
+}
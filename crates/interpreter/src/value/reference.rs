@@ -0,0 +1,46 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use pyret_lexer::ast::AnnotationType;
+
+use super::PyretValue;
+
+/// A mutable cell stored in a `ref` field, which is shared by every copy of
+/// the object or data instance containing it.
+#[derive(Clone)]
+pub struct PyretRef {
+    cell: Rc<RefCell<PyretValue>>,
+    /// The annotation of the field, which is checked on every update.
+    pub annotation: Option<Rc<AnnotationType>>,
+}
+
+impl PyretRef {
+    #[must_use]
+    pub fn new(value: PyretValue, annotation: Option<Rc<AnnotationType>>) -> Self {
+        Self {
+            cell: Rc::new(RefCell::new(value)),
+            annotation,
+        }
+    }
+
+    /// Returns the current value of the cell.
+    #[must_use]
+    pub fn get(&self) -> PyretValue {
+        self.cell.borrow().clone()
+    }
+
+    pub fn set(&self, value: PyretValue) {
+        *self.cell.borrow_mut() = value;
+    }
+}
+
+impl PartialEq for PyretRef {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.cell, &other.cell)
+    }
+}
+
+impl fmt::Display for PyretRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cell.borrow())
+    }
+}
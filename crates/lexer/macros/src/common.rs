@@ -5,7 +5,7 @@ pub fn expand(input: TokenStream) -> TokenStream {
     let input = proc_macro2::TokenStream::from(input);
 
     let expanded = quote! {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
         #input
     };
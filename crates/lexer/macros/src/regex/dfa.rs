@@ -0,0 +1,171 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    ops::RangeInclusive,
+};
+
+use regex::internal::{Inst, Program};
+
+/// A deterministic automaton built from a byte-based regex program, where
+/// each state is a set of threads of the program.
+///
+/// Within a single pattern, threads are ordered by priority, so that lazy
+/// repetitions stop at the earliest match. Across patterns, the longest match
+/// wins, and ties are broken in favor of the pattern that comes first.
+pub struct Dfa {
+    pub states: Vec<DfaState>,
+}
+
+pub struct DfaState {
+    /// The byte ranges that lead to another state.
+    pub transitions: Vec<(RangeInclusive<u8>, usize)>,
+    /// The index of the pattern that matches when ending in this state.
+    pub accept: Option<usize>,
+}
+
+/// The threads of a state, in order of priority, along with the pattern it
+/// accepts.
+type StateKey = (Vec<usize>, Option<usize>);
+
+impl Dfa {
+    pub fn new(program: &Program) -> Result<Self, String> {
+        let patterns = inst_patterns(program);
+
+        let mut keys = vec![closure(program, &patterns, &[program.start])?];
+        let mut ids = HashMap::from([(keys[0].clone(), 0)]);
+
+        let mut states = Vec::new();
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(id) = queue.pop_front() {
+            let (threads, accept) = keys[id].clone();
+
+            let mut transitions: Vec<(RangeInclusive<u8>, usize)> = Vec::new();
+
+            for byte in 0..=u8::MAX {
+                let targets = threads
+                    .iter()
+                    .filter_map(|&pc| match &program[pc] {
+                        Inst::Bytes(inst) if (inst.start..=inst.end).contains(&byte) => {
+                            Some(inst.goto)
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                if targets.is_empty() {
+                    continue;
+                }
+
+                let key = closure(program, &patterns, &targets)?;
+
+                if key.0.is_empty() && key.1.is_none() {
+                    continue;
+                }
+
+                let next = *ids.entry(key.clone()).or_insert_with(|| {
+                    keys.push(key);
+                    queue.push_back(keys.len() - 1);
+
+                    keys.len() - 1
+                });
+
+                match transitions.last_mut() {
+                    Some((range, last)) if *last == next && *range.end() + 1 == byte => {
+                        *range = *range.start()..=byte;
+                    }
+                    _ => transitions.push((byte..=byte, next)),
+                }
+            }
+
+            if states.len() <= id {
+                states.resize_with(id + 1, || DfaState {
+                    transitions: Vec::new(),
+                    accept: None,
+                });
+            }
+
+            states[id] = DfaState {
+                transitions,
+                accept,
+            };
+        }
+
+        Ok(Self { states })
+    }
+}
+
+/// Follows the empty transitions from the targets, in order of priority.
+///
+/// Once a pattern has matched, its lower priority threads are dropped, since
+/// they could only ever lead to a match that is not preferred.
+fn closure(
+    program: &Program,
+    patterns: &[Option<usize>],
+    targets: &[usize],
+) -> Result<StateKey, String> {
+    let mut threads = Vec::new();
+    let mut matched = HashSet::new();
+    let mut visited = HashSet::new();
+
+    let mut stack = targets.iter().rev().copied().collect::<Vec<_>>();
+
+    while let Some(pc) = stack.pop() {
+        if !visited.insert(pc) {
+            continue;
+        }
+
+        match &program[pc] {
+            Inst::Match(pattern) => {
+                matched.insert(*pattern);
+            }
+            Inst::Save(inst) => stack.push(inst.goto),
+            Inst::Split(inst) => {
+                stack.push(inst.goto2);
+                stack.push(inst.goto1);
+            }
+            Inst::Bytes(..) => {
+                if patterns[pc].is_none_or(|pattern| !matched.contains(&pattern)) {
+                    threads.push(pc);
+                }
+            }
+            Inst::EmptyLook(..) => return Err(String::from("Assertions are not supported")),
+            Inst::Char(..) | Inst::Ranges(..) => {
+                return Err(String::from("Expected a byte-based program"));
+            }
+        }
+    }
+
+    Ok((threads, matched.into_iter().min()))
+}
+
+/// Finds the pattern each instruction belongs to, which is the only pattern it
+/// can lead to a match of.
+fn inst_patterns(program: &Program) -> Vec<Option<usize>> {
+    (0..program.len())
+        .map(|start| {
+            let mut matches = HashSet::new();
+            let mut visited = HashSet::new();
+            let mut stack = vec![start];
+
+            while let Some(pc) = stack.pop() {
+                if !visited.insert(pc) {
+                    continue;
+                }
+
+                match &program[pc] {
+                    Inst::Match(pattern) => {
+                        matches.insert(*pattern);
+                    }
+                    Inst::Save(inst) => stack.push(inst.goto),
+                    Inst::Split(inst) => stack.extend([inst.goto1, inst.goto2]),
+                    Inst::Bytes(inst) => stack.push(inst.goto),
+                    Inst::Char(inst) => stack.push(inst.goto),
+                    Inst::Ranges(inst) => stack.push(inst.goto),
+                    Inst::EmptyLook(inst) => stack.push(inst.goto),
+                }
+            }
+
+            (matches.len() == 1).then(|| matches.into_iter().next().unwrap())
+        })
+        .collect()
+}
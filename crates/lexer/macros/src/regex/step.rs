@@ -3,16 +3,10 @@ use quote::{format_ident, quote};
 
 use super::item::LexerItem;
 
-pub fn return_nodes(nodes: &[LexerItem], use_offset: bool, i_slice: bool) -> TokenStream {
-    let input = if i_slice {
-        let range = if use_offset { quote!(..=) } else { quote!(..) };
-
-        quote!(&input[#range i])
-    } else {
-        quote!(&input[..=last_match.1])
-    };
-
-    let mut last_step = quote!((Box::from(#input), state)?);
+/// Constructs the nodes of a match, from the leaf parsing the matched input
+/// up to the node being lexed.
+pub fn return_nodes(nodes: &[LexerItem]) -> TokenStream {
+    let mut last_step = quote!((Box::from(&input[..length]), state)?);
 
     for LexerItem {
         ident,
@@ -1,5 +1,5 @@
 use crate::{
-    ast::{CheckDeclaration, LetDeclaration},
+    ast::{CheckDeclaration, DataDeclaration, FunctionDeclaration, LetDeclaration},
     prelude::*,
 };
 
@@ -7,6 +7,7 @@ use crate::{
 #[derive(Node)]
 pub enum DeclarationStatement {
     Check(CheckDeclaration),
-    // Function(FunctionDeclaration),
+    Data(DataDeclaration),
+    Function(FunctionDeclaration),
     Let(LetDeclaration),
 }
@@ -1,8 +1,11 @@
 use super::ParenthesisExpression;
 use crate::{
     ast::{
-        ApplicationExpression, BinaryOperatorExpression, BlockExpression, DotExpression,
-        IdentifierExpression, LiteralExpression, Statement,
+        ApplicationExpression, AskExpression, AssignExpression, ColonEqualSymbol, BinaryOperatorExpression, BlockExpression,
+        CasesExpression,
+        CurlyLambdaExpression, DotExpression, IdentifierExpression, IfExpression,
+        LambdaExpression, LiteralExpression, MethodExpression, ObjectExpression, Statement,
+        TupleExpression, TupleGetExpression, GetBangExpression, UpdateExpression,
     },
     prelude::*,
 };
@@ -12,11 +15,23 @@ use crate::{
 #[transform(transform)]
 pub enum ExpressionStatement {
     Application(ApplicationExpression),
+    Assign(AssignExpression),
     Block(BlockExpression),
+    Lambda(LambdaExpression),
+    CurlyLambda(CurlyLambdaExpression),
+    Method(MethodExpression),
+    If(IfExpression),
+    Ask(AskExpression),
+    Cases(CasesExpression),
     Literal(LiteralExpression),
+    Object(ObjectExpression),
+    Tuple(TupleExpression),
+    TupleGet(TupleGetExpression),
     BinaryOperator(BinaryOperatorExpression),
     Identifier(IdentifierExpression),
     Dot(DotExpression),
+    GetBang(GetBangExpression),
+    Update(UpdateExpression),
     Parenthesis(ParenthesisExpression),
 }
 
@@ -26,24 +41,31 @@ impl ExpressionStatement {
     /// Will return an [`Error`] if the expression is not valid.
     #[inline]
     pub fn transform(self, state: &mut LexerState) -> PyretResult<Self> {
-        match self {
-            Self::Identifier(ident)
-                if {
-                    let end = ident.end();
+        // Anything directly followed by an opening parenthesis is applied.
+        if is_applied(&self, state) {
+            let application = ApplicationExpression::new(self, state)?;
 
-                    end < state.source.len() && &state.source[end..=end] == "("
-                } =>
-            {
-                let application = ApplicationExpression::new(ident, state)?;
+            return Self::Application(application).transform(state);
+        }
 
-                return Ok(Self::Application(application));
-            }
+        if matches!(self, Self::Identifier(..)) && state.lex::<ColonEqualSymbol>()?.is_some() {
+            let Self::Identifier(ident) = self else {
+                unreachable!()
+            };
 
-            _ => state.consume(Statement::Expression(self)),
+            return Ok(Self::Assign(AssignExpression::new(ident, state)?));
         }
 
+        state.consume(Statement::Expression(self));
+
         let token = if let Some(binary_op) = state.lex::<BinaryOperatorExpression>()? {
             Self::BinaryOperator(binary_op).transform(state)?
+        } else if let Some(tuple_get) = state.lex::<TupleGetExpression>()? {
+            Self::TupleGet(tuple_get).transform(state)?.transform(state)?
+        } else if let Some(update) = state.lex::<UpdateExpression>()? {
+            Self::Update(update).transform(state)?
+        } else if let Some(get_bang) = state.lex::<GetBangExpression>()? {
+            Self::GetBang(get_bang).transform(state)?.transform(state)?
         } else if let Some(dot) = state.lex::<DotExpression>()? {
             Self::Dot(dot).transform(state)?.transform(state)?
         } else if let Statement::Expression(expr) = state.pop()? {
@@ -55,3 +77,9 @@ impl ExpressionStatement {
         Ok(token)
     }
 }
+
+fn is_applied(callee: &impl Token, state: &LexerState) -> bool {
+    let end = callee.end();
+
+    end < state.source.len() && &state.source[end..=end] == "("
+}
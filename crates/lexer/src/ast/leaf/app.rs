@@ -1,14 +1,23 @@
 use crate::{
-    ast::{ExpressionStatement, IdentifierExpression, Statement, SymbolStatement},
+    ast::{
+        CloseParenSymbol, ColonSymbol, CommaSymbol, DotExpression, ExpressionStatement,
+        FromSymbol, Function,
+        IdentifierExpression, LambdaExpression, OpenParenSymbol, Parameter, ShadowSymbol,
+        Statement, SymbolStatement, ThinArrowSymbol, TypeAnnotation,
+    },
     prelude::*,
 };
 
 /// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3aapp-expr)>
+///
+/// Only `for` expressions are lexed directly as applications, since they are
+/// sugar for applying the iterator to a lambda.
 #[common]
 #[derive(Leaf)]
+#[regex(r"for")]
 pub struct ApplicationExpression {
     span: (usize, usize),
-    pub ident: IdentifierExpression,
+    pub callee: Box<ExpressionStatement>,
     pub args: Vec<ExpressionStatement>,
 }
 
@@ -16,12 +25,16 @@ impl ApplicationExpression {
     /// # Errors
     ///
     /// Will return an [`PyretErrorKind`] if the expression is not valid.
-    pub fn new(ident: IdentifierExpression, state: &mut LexerState) -> PyretResult<Self> {
+    pub fn new(callee: ExpressionStatement, state: &mut LexerState) -> PyretResult<Self> {
         let mut args = Vec::new();
 
-        state.current_position = ident.end() + 1;
+        state.current_position = callee.end() + 1;
+
+        loop {
+            let Some(stmt) = state.lex::<Statement>()? else {
+                return Err(state.unclosed(callee.end()));
+            };
 
-        while let Some(stmt) = state.lex::<Statement>()? {
             state.current_position = stmt.end();
 
             if let Statement::Symbol(SymbolStatement::Comma(..)) = stmt {
@@ -34,14 +47,125 @@ impl ApplicationExpression {
             if let Statement::Expression(expr) = stmt {
                 args.push(expr);
             } else {
-                todo!("Handle non-expression statements in application expressions");
+                return Err(PyretErrorKind::Unexpected {
+                    expected: Box::from(ExpressionStatement::NODE_NAME),
+                    found: stmt.serialize(),
+                });
             }
         }
 
         Ok(Self {
-            span: (ident.start(), state.current_position),
-            ident,
+            span: (callee.start(), state.current_position),
+            callee: Box::new(callee),
             args,
         })
     }
 }
+
+/// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3afor-expr)>
+///
+/// `for fold(acc from 0, x from xs): acc + x end` is lexed as
+/// `fold(lam(acc, x): acc + x end, 0, xs)`.
+impl TokenParser for ApplicationExpression {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        let start_position = state.next_position;
+
+        state.current_position = start_position + 3;
+
+        let mut iterator = ExpressionStatement::Identifier(state.try_lex::<IdentifierExpression>()?);
+
+        while state.source[state.current_position..].starts_with('.') {
+            state.consume(Statement::Expression(iterator));
+
+            let dot = state.try_lex::<DotExpression>()?;
+
+            state.current_position = dot.end();
+
+            iterator = ExpressionStatement::Dot(dot);
+        }
+
+        state.current_position = state.try_lex::<OpenParenSymbol>()?.end();
+
+        let mut params = Vec::new();
+        let mut args = Vec::new();
+
+        if let Some(closing) = state.lex::<CloseParenSymbol>()? {
+            state.current_position = closing.end();
+        } else {
+            loop {
+                let shadow_symbol = state.lex::<ShadowSymbol>()?;
+
+                if let Some(shadow_symbol) = &shadow_symbol {
+                    state.current_position = shadow_symbol.end();
+                }
+
+                let ident = state.try_lex::<IdentifierExpression>()?;
+                let annotation = state.lex::<TypeAnnotation>()?;
+
+                if let Some(annotation) = &annotation {
+                    state.current_position = annotation.end();
+                }
+
+                state.current_position = state.try_lex::<FromSymbol>()?.end();
+
+                let value = state.try_lex::<ExpressionStatement>()?;
+
+                state.current_position = value.end();
+
+                params.push(Parameter {
+                    ident,
+                    annotation,
+                    is_ref: false,
+                    shadows: shadow_symbol.is_some(),
+                });
+                args.push(value);
+
+                if let Some(comma) = state.lex::<CommaSymbol>()? {
+                    state.current_position = comma.end();
+                } else {
+                    state.current_position = state.try_lex::<CloseParenSymbol>()?.end();
+
+                    break;
+                }
+            }
+        }
+
+        let return_annotation = if let Some(arrow) = state.lex::<ThinArrowSymbol>()? {
+            state.current_position = arrow.end();
+
+            Some(TypeAnnotation::parse(arrow.start(), state)?)
+        } else {
+            None
+        };
+
+        state.current_position = state.try_lex::<ColonSymbol>()?.end();
+
+        let (body, _) = state.lex_block(start_position, |symbol| matches!(symbol, SymbolStatement::End(..)))?;
+
+        if body.is_empty() {
+            state.throw_late(PyretErrorKind::EmptyBlock {
+                ident: (start_position..start_position + 3).into(),
+            });
+        }
+
+        let span = (start_position, state.current_position);
+
+        let lambda = LambdaExpression::new(
+            span,
+            Function {
+                generics: Vec::new(),
+                params,
+                return_annotation,
+                doc: None,
+                body,
+            },
+        );
+
+        Ok(Self {
+            span,
+            callee: Box::new(iterator),
+            args: [ExpressionStatement::Lambda(lambda)].into_iter().chain(args).collect(),
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{
+    ast::{ColonEqualSymbol, ExpressionStatement, IdentifierExpression},
+    prelude::*,
+};
+
+/// <https://www.pyret.org/docs/latest/Statements.html#(part._s~3aassign-stmt)>
+#[common]
+#[derive(Leaf)]
+pub struct AssignExpression {
+    span: (usize, usize),
+    pub ident: IdentifierExpression,
+    pub value: Box<ExpressionStatement>,
+}
+
+impl AssignExpression {
+    /// Parses the `:=` following an identifier, and the value being assigned.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the value is not valid.
+    pub fn new(ident: IdentifierExpression, state: &mut LexerState) -> PyretResult<Self> {
+        state.current_position = ident.end();
+        state.current_position = state.try_lex::<ColonEqualSymbol>()?.end();
+
+        let value = Box::new(state.try_lex::<ExpressionStatement>()?);
+
+        state.current_position = value.end();
+
+        Ok(Self {
+            span: (ident.start(), value.end()),
+            ident,
+            value,
+        })
+    }
+}
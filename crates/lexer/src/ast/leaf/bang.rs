@@ -0,0 +1,73 @@
+use crate::{
+    ast::{CloseBraceSymbol, ExpressionStatement, IdentifierExpression, ObjectField, Statement},
+    prelude::*,
+};
+
+/// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3aget-bang-expr)>
+#[common]
+#[derive(Leaf)]
+#[regex(r"!")]
+pub struct GetBangExpression {
+    span: (usize, usize),
+    pub object: Box<ExpressionStatement>,
+    pub property: IdentifierExpression,
+}
+
+impl TokenParser for GetBangExpression {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        // Skip "!"
+        state.skip(1);
+
+        let property = state.try_lex::<IdentifierExpression>()?;
+
+        let object = pop_object(state)?;
+
+        Ok(Self {
+            span: (object.start(), property.end()),
+            object,
+            property,
+        })
+    }
+}
+
+/// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3aupdate-expr)>
+#[common]
+#[derive(Leaf)]
+#[regex(r"!\{")]
+pub struct UpdateExpression {
+    span: (usize, usize),
+    pub object: Box<ExpressionStatement>,
+    pub fields: Vec<ObjectField>,
+}
+
+impl TokenParser for UpdateExpression {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        // Skip "!{"
+        state.skip(2);
+
+        let fields = ObjectField::parse_list(state)?;
+
+        state.current_position = state.try_lex::<CloseBraceSymbol>()?.end();
+
+        let end = state.current_position;
+
+        let object = pop_object(state)?;
+
+        Ok(Self {
+            span: (object.start(), end),
+            object,
+            fields,
+        })
+    }
+}
+
+fn pop_object(state: &mut LexerState) -> PyretResult<Box<ExpressionStatement>> {
+    match state.pop()? {
+        Statement::Expression(expr) => Ok(Box::new(expr)),
+        stmt => Err(PyretErrorKind::ExpectedObject {
+            left: stmt.serialize(),
+        }),
+    }
+}
@@ -1,5 +1,8 @@
+use std::ops::Range;
+
 use crate::{
-    ast::{ExpressionStatement, Statement},
+    ast::{ExpressionStatement, ParenthesisExpression, Statement},
+    error::SerializedToken,
     prelude::*,
 };
 
@@ -19,46 +22,107 @@ pub enum BinaryOperation {
     And,
     Or,
     Is,
+    IsNot,
+    IsEqual,
+    IsEqualNow,
+    IsIdentical,
     IsRoughly,
+    IsRefinement,
+    IsNotRefinement,
+    Satisfies,
+    Violates,
+    Raises,
+    RaisesOtherThan,
+    DoesNotRaise,
+    RaisesSatisfies,
+    RaisesViolates,
 }
 
 impl BinaryOperation {
     #[must_use]
-    pub const fn priority(&self) -> u8 {
-        match self {
-            Self::Plus | Self::Minus => 0,
-            Self::Times | Self::Divide => 1,
-            Self::LessThan
-            | Self::LessThanOrEqual
-            | Self::GreaterThan
-            | Self::GreaterThanOrEqual
-            | Self::Equal
-            | Self::NotEqual => 2,
-            Self::And | Self::Or => 3,
-            Self::Is | Self::IsRoughly => 4,
-        }
+    pub const fn is_testing(&self) -> bool {
+        matches!(
+            self,
+            Self::Is
+                | Self::IsNot
+                | Self::IsEqual
+                | Self::IsEqualNow
+                | Self::IsIdentical
+                | Self::IsRoughly
+                | Self::IsRefinement
+                | Self::IsNotRefinement
+                | Self::Satisfies
+                | Self::Violates
+                | Self::Raises
+                | Self::RaisesOtherThan
+                | Self::DoesNotRaise
+                | Self::RaisesSatisfies
+                | Self::RaisesViolates
+        )
     }
 
+    /// Whether the operator tests that evaluating its left side raises an
+    /// error, rather than testing its value.
     #[must_use]
-    pub const fn is_testing(&self) -> bool {
-        matches!(self, Self::Is | Self::IsRoughly)
+    pub const fn is_raising(&self) -> bool {
+        matches!(
+            self,
+            Self::Raises | Self::RaisesOtherThan | Self::RaisesSatisfies | Self::RaisesViolates
+        )
     }
 
     #[must_use]
-    pub fn check_grouping(&self, other: &Self) -> bool {
-        self == other || self.is_testing() || other.is_testing()
+    pub const fn symbol(&self) -> &'static str {
+        match self {
+            Self::Plus => "+",
+            Self::Minus => "-",
+            Self::Times => "*",
+            Self::Divide => "/",
+            Self::LessThan => "<",
+            Self::LessThanOrEqual => "<=",
+            Self::GreaterThan => ">",
+            Self::GreaterThanOrEqual => ">=",
+            Self::Equal => "==",
+            Self::NotEqual => "<>",
+            Self::And => "and",
+            Self::Or => "or",
+            Self::Is => "is",
+            Self::IsNot => "is-not",
+            Self::IsEqual => "is==",
+            Self::IsEqualNow => "is=~",
+            Self::IsIdentical => "is<=>",
+            Self::IsRoughly => "is-roughly",
+            Self::IsRefinement => "is%",
+            Self::IsNotRefinement => "is-not%",
+            Self::Satisfies => "satisfies",
+            Self::Violates => "violates",
+            Self::Raises => "raises",
+            Self::RaisesOtherThan => "raises-other-than",
+            Self::DoesNotRaise => "does-not-raise",
+            Self::RaisesSatisfies => "raises-satisfies",
+            Self::RaisesViolates => "raises-violates",
+        }
     }
+
 }
 
 /// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3abinop-expr)>
 #[common]
 #[derive(Leaf)]
-#[regex(r"[+\-*/]|<=?|>=?|==|<>|and|or|is(-roughly)?")]
+// Operators that are words must be followed by whitespace, so that they are
+// not confused with the start of a name like `is-empty`. Within the pattern,
+// the first alternative that matches is taken, so `<>` comes before `<=?`.
+#[regex(
+    r"[+\-*/]|<>|<=?|>=?|==|is(-not)?%|is(==|=~|<=>)|does-not-raise|(and|or|is(-roughly|-not)?|satisfies|violates|raises(-other-than|-satisfies|-violates)?)\s"
+)]
 pub struct BinaryOperatorExpression {
     span: (usize, usize),
     pub left: Box<ExpressionStatement>,
     pub operator: BinaryOperation,
-    pub right: Box<ExpressionStatement>,
+    /// The predicate of `is%(pred)` and `is-not%(pred)`.
+    pub refinement: Option<Box<ExpressionStatement>>,
+    /// The right side, which only `does-not-raise` goes without.
+    pub right: Option<Box<ExpressionStatement>>,
 }
 
 impl BinaryOperatorExpression {
@@ -68,25 +132,86 @@ impl BinaryOperatorExpression {
         operator: BinaryOperation,
         right: ExpressionStatement,
     ) -> Self {
-        match (left, right) {
-            (left, ExpressionStatement::BinaryOperator(mut binary_op))
-                if operator.priority() < binary_op.operator.priority() =>
-            {
-                binary_op.left = Box::new(ExpressionStatement::BinaryOperator(Self::new(
-                    left,
-                    operator,
-                    *binary_op.left,
-                )));
+        Self {
+            span: (left.start(), right.end()),
+            left: Box::new(left),
+            operator,
+            refinement: None,
+            right: Some(Box::new(right)),
+        }
+    }
+
+    /// Groups `left operator right`, where the right side is an operator
+    /// expression without parentheses, which was lexed as if it grouped to the
+    /// right. Pyret does not have precedence: chains of the same operator
+    /// group to the left, testing operators apply to the whole expressions on
+    /// either side of them, and any other mix of operators is an error.
+    fn group(
+        left: ExpressionStatement,
+        operator: BinaryOperation,
+        operator_span: Range<usize>,
+        refinement: Option<Box<ExpressionStatement>>,
+        mut right: Self,
+        state: &LexerState,
+    ) -> PyretResult<Self> {
+        if operator.is_testing() && !right.operator.is_testing() {
+            let mut binary_op = Self::new(left, operator, ExpressionStatement::BinaryOperator(right));
+
+            binary_op.refinement = refinement;
+
+            return Ok(binary_op);
+        }
+
+        let same = right.operator == operator && !operator.is_testing();
+
+        if !same && (operator.is_testing() || !right.operator.is_testing()) {
+            return Err(mixed_operators(
+                &operator,
+                operator_span,
+                &right.operator,
+                right.operator_span(state.source),
+            ));
+        }
+
+        let grouped = match *right.left {
+            ExpressionStatement::BinaryOperator(right_left) => {
+                Self::group(left, operator, operator_span, refinement, right_left, state)?
+            }
+            right_left => {
+                let mut binary_op = Self::new(left, operator, right_left);
+
+                binary_op.refinement = refinement;
 
                 binary_op
             }
+        };
 
-            (left, right) => Self {
-                span: (left.start(), right.end()),
-                left: Box::new(left),
-                operator,
-                right: Box::new(right),
-            },
+        right.span.0 = grouped.start();
+        right.left = Box::new(ExpressionStatement::BinaryOperator(grouped));
+
+        Ok(right)
+    }
+
+    /// Finds the operator, which is the first thing after the left side.
+    fn operator_span(&self, source: &str) -> Range<usize> {
+        let end = self.left.end();
+
+        let start = source[end..]
+            .find(|c: char| !c.is_ascii_whitespace())
+            .map_or(end, |offset| end + offset);
+
+        start..start + self.operator.symbol().len()
+    }
+
+    /// Creates an operator without a right side, like `does-not-raise`.
+    #[must_use]
+    pub fn postfix(left: ExpressionStatement, operator: BinaryOperation, end: usize) -> Self {
+        Self {
+            span: (left.start(), end),
+            left: Box::new(left),
+            operator,
+            refinement: None,
+            right: None,
         }
     }
 }
@@ -98,6 +223,8 @@ impl TokenParser for BinaryOperatorExpression {
 
         let no_whitespace = state.current_position == start_position;
 
+        let input = input.trim_end();
+
         let length = input.len();
 
         if no_whitespace {
@@ -106,7 +233,7 @@ impl TokenParser for BinaryOperatorExpression {
             });
         }
 
-        let operator = match &*input {
+        let operator = match input {
             "+" => BinaryOperation::Plus,
             "-" => BinaryOperation::Minus,
             "*" => BinaryOperation::Times,
@@ -120,35 +247,140 @@ impl TokenParser for BinaryOperatorExpression {
             "and" => BinaryOperation::And,
             "or" => BinaryOperation::Or,
             "is" => BinaryOperation::Is,
+            "is-not" => BinaryOperation::IsNot,
+            "is==" => BinaryOperation::IsEqual,
+            "is=~" => BinaryOperation::IsEqualNow,
+            "is<=>" => BinaryOperation::IsIdentical,
             "is-roughly" => BinaryOperation::IsRoughly,
+            "is%" => BinaryOperation::IsRefinement,
+            "is-not%" => BinaryOperation::IsNotRefinement,
+            "satisfies" => BinaryOperation::Satisfies,
+            "violates" => BinaryOperation::Violates,
+            "raises" => BinaryOperation::Raises,
+            "raises-other-than" => BinaryOperation::RaisesOtherThan,
+            "does-not-raise" => BinaryOperation::DoesNotRaise,
+            "raises-satisfies" => BinaryOperation::RaisesSatisfies,
+            "raises-violates" => BinaryOperation::RaisesViolates,
             op => unreachable!("{{{op}}}"),
         };
 
         state.skip(length);
 
-        let right = match state.try_lex::<ExpressionStatement>()? {
-            ExpressionStatement::BinaryOperator(binary_op)
-                if !operator.check_grouping(&binary_op.operator) =>
-            {
-                todo!("error about groups levels")
+        let refinement = match operator {
+            BinaryOperation::IsRefinement | BinaryOperation::IsNotRefinement => {
+                let predicate = state.try_lex::<ParenthesisExpression>()?;
+
+                state.current_position = predicate.end();
+
+                Some(predicate.expr)
             }
-            expr => expr,
+            _ => None,
         };
 
+        if operator == BinaryOperation::DoesNotRaise {
+            let left = match state.pop()? {
+                Statement::Expression(expr) => expr,
+                stmt => return Err(expected_expression(&stmt)),
+            };
+
+            return Ok(Self::postfix(left, operator, start_position + length));
+        }
+
+        let operator_span = start_position..start_position + length;
+
+        let right = state.try_lex::<ExpressionStatement>()?;
+
         let left = match state.pop()? {
-            Statement::Expression(expr) => match &expr {
-                ExpressionStatement::BinaryOperator(binary_op) => {
-                    if operator.check_grouping(&binary_op.operator) {
-                        expr
-                    } else {
-                        todo!("error about groups levels")
-                    }
-                }
-                _ => expr,
+            Statement::Expression(expr) => expr,
+            stmt => return Err(expected_expression(&stmt)),
+        };
+
+        if let ExpressionStatement::BinaryOperator(binary_op) = &left {
+            let grouped = binary_op.operator == operator && !operator.is_testing()
+                || operator.is_testing() && !binary_op.operator.is_testing();
+
+            if !grouped {
+                return Err(mixed_operators(
+                    &binary_op.operator,
+                    binary_op.operator_span(state.source),
+                    &operator,
+                    operator_span,
+                ));
+            }
+        }
+
+        match right {
+            ExpressionStatement::BinaryOperator(right) => {
+                Self::group(left, operator, operator_span, refinement, right, state)
+            }
+            right => {
+                let mut binary_op = Self::new(left, operator, right);
+
+                binary_op.refinement = refinement;
+
+                Ok(binary_op)
+            }
+        }
+    }
+}
+
+/// The error for an operator whose left side is not an expression, like a
+/// declaration.
+fn expected_expression(stmt: &Statement) -> PyretErrorKind {
+    PyretErrorKind::Unexpected {
+        expected: Box::from(ExpressionStatement::NODE_NAME),
+        found: stmt.serialize(),
+    }
+}
+
+/// The error for two operators that cannot be grouped, where tests cannot be
+/// grouped at all, since they are not expressions.
+fn mixed_operators(
+    left: &BinaryOperation,
+    left_span: Range<usize>,
+    right: &BinaryOperation,
+    right_span: Range<usize>,
+) -> PyretErrorKind {
+    if left.is_testing() && right.is_testing() {
+        return PyretErrorKind::Unexpected {
+            expected: Box::from(ExpressionStatement::NODE_NAME),
+            found: SerializedToken {
+                name: Box::from(BinaryOperatorExpression::NODE_NAME),
+                span: right_span,
             },
-            _ => todo!("expr pls"),
         };
+    }
+
+    PyretErrorKind::MixedOperators {
+        left: Box::from(left.symbol()),
+        right: Box::from(right.symbol()),
+        left_span: left_span.into(),
+        right_span: right_span.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BinaryOperation;
+    use crate::{
+        ast::{ExpressionStatement, Statement},
+        lex,
+    };
+
+    fn operator(source: &str) -> BinaryOperation {
+        match &lex(source).unwrap()[..] {
+            [Statement::Expression(ExpressionStatement::BinaryOperator(binary_op))] => {
+                binary_op.operator.clone()
+            }
+            _ => panic!("expected a single operator expression"),
+        }
+    }
 
-        Ok(Self::new(left, operator, right))
+    #[test]
+    fn comparison_operators() {
+        assert_eq!(operator("1 <> 2"), BinaryOperation::NotEqual);
+        assert_eq!(operator("1 < 2"), BinaryOperation::LessThan);
+        assert_eq!(operator("1 <= 2"), BinaryOperation::LessThanOrEqual);
+        assert_eq!(operator("1 >= 2"), BinaryOperation::GreaterThanOrEqual);
     }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    ast::{Statement, SymbolStatement},
+    ast::{DeclarationStatement, Statement, SymbolStatement},
     prelude::*,
 };
 
@@ -19,29 +19,28 @@ impl TokenParser for BlockExpression {
 
         state.current_position = start_position + 6;
 
-        let mut body = Vec::new();
-
-        let mut end_position = None;
-
-        while let Some(stmt) = state.lex::<Statement>()? {
-            state.current_position = stmt.end();
-
-            if let Statement::Symbol(SymbolStatement::End(end_symbol)) = &stmt {
-                end_position = Some(end_symbol.end());
-
-                break;
+        let (body, end) =
+            state.lex_block(start_position, |symbol| matches!(symbol, SymbolStatement::End(..)))?;
+
+        match body.last() {
+            None => state.throw_late(PyretErrorKind::EmptyBlock {
+                ident: (start_position..start_position + 6).into(),
+            }),
+            Some(Statement::Expression(..)) => {}
+            // The span of a let declaration starts at its value.
+            Some(Statement::Declaration(DeclarationStatement::Let(decl))) => {
+                state.throw_late(PyretErrorKind::BlockEndsInDeclaration {
+                    span: (decl.binding.start()..decl.end()).into(),
+                });
             }
-
-            body.push(stmt);
+            Some(stmt) => state.throw_late(PyretErrorKind::BlockEndsInDeclaration {
+                span: stmt.span().into(),
+            }),
         }
 
-        if let Some(end_position) = end_position {
-            Ok(Self {
-                span: (start_position, end_position),
-                body,
-            })
-        } else {
-            todo!("Handle unclosed block expressions")
-        }
+        Ok(Self {
+            span: (start_position, end.end()),
+            body,
+        })
     }
 }
@@ -0,0 +1,127 @@
+use crate::{
+    ast::{
+        CloseParenSymbol, ColonSymbol, ElseArrowSymbol, ExpressionStatement, FatArrowSymbol,
+        IdentifierExpression, OpenParenSymbol, Parameter, Statement, SymbolStatement,
+        TypeAnnotation,
+    },
+    prelude::*,
+};
+
+#[common]
+pub struct CasesBranch {
+    pub ident: IdentifierExpression,
+    /// The names bound to the fields of the variant, or [`None`] if the
+    /// branch matches a singleton variant.
+    pub bindings: Option<Vec<Parameter>>,
+    pub body: Vec<Statement>,
+}
+
+/// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3acases-expr)>
+#[common]
+#[derive(Leaf)]
+#[regex(r"cases\s*\(")]
+pub struct CasesExpression {
+    span: (usize, usize),
+    pub annotation: TypeAnnotation,
+    pub value: Box<ExpressionStatement>,
+    pub branches: Vec<CasesBranch>,
+    pub else_body: Option<Vec<Statement>>,
+}
+
+impl TokenParser for CasesExpression {
+    #[inline]
+    fn parse_token(input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        let start_position = state.next_position;
+
+        state.current_position = start_position + input.len();
+
+        let annotation = TypeAnnotation::parse(state.current_position, state)?;
+
+        state.current_position = state.try_lex::<CloseParenSymbol>()?.end();
+
+        let value = state.try_lex::<ExpressionStatement>()?;
+
+        state.current_position = state.try_lex::<ColonSymbol>()?.end();
+
+        let mut branches = Vec::new();
+        let mut else_body = None;
+
+        let mut next = state.try_lex::<SymbolStatement>()?;
+
+        loop {
+            state.current_position = next.end();
+
+            match next {
+                SymbolStatement::End(..) => break,
+                SymbolStatement::Bar(..) => {
+                    if let Some(else_arrow) = state.lex::<ElseArrowSymbol>()? {
+                        state.current_position = else_arrow.end();
+
+                        let (body, closing) =
+                            state.lex_block(start_position, |symbol| matches!(symbol, SymbolStatement::End(..)))?;
+
+                        if body.is_empty() {
+                            state.throw_late(PyretErrorKind::EmptyBlock {
+                                ident: closing.span().into(),
+                            });
+                        }
+
+                        else_body = Some(body);
+
+                        break;
+                    }
+
+                    let ident = state.try_lex::<IdentifierExpression>()?;
+
+                    state.current_position = ident.end();
+
+                    let bindings = parse_bindings(state)?;
+
+                    state.current_position = state.try_lex::<FatArrowSymbol>()?.end();
+
+                    let (body, closing) = state.lex_block(start_position, |symbol| {
+                        matches!(symbol, SymbolStatement::End(..) | SymbolStatement::Bar(..))
+                    })?;
+
+                    if body.is_empty() {
+                        state.throw_late(PyretErrorKind::EmptyBlock {
+                            ident: ident.span().into(),
+                        });
+                    }
+
+                    branches.push(CasesBranch {
+                        ident,
+                        bindings,
+                        body,
+                    });
+
+                    next = closing;
+                }
+                symbol => {
+                    return Err(PyretErrorKind::Unexpected {
+                        expected: Box::from(SymbolStatement::NODE_NAME),
+                        found: symbol.serialize(),
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            span: (start_position, state.current_position),
+            annotation,
+            value: Box::new(value),
+            branches,
+            else_body,
+        })
+    }
+}
+
+fn parse_bindings(state: &mut LexerState) -> PyretResult<Option<Vec<Parameter>>> {
+    let Some(open_paren) = state.lex::<OpenParenSymbol>()? else {
+        return Ok(None);
+    };
+
+    state.current_position = open_paren.end();
+
+    Parameter::parse_list(state).map(Some)
+}
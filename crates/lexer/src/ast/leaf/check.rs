@@ -5,9 +5,11 @@ use crate::{
 };
 
 /// <https://www.pyret.org/docs/latest/testing.html>
+///
+/// Also parses `examples:` blocks, which are checked the same way.
 #[common]
 #[derive(Leaf)]
-#[regex(r"check")]
+#[regex(r"check|examples")]
 pub struct CheckDeclaration {
     span: (usize, usize),
     pub label: Option<Box<str>>,
@@ -16,10 +18,10 @@ pub struct CheckDeclaration {
 
 impl TokenParser for CheckDeclaration {
     #[inline]
-    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+    fn parse_token(input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
         let start_position = state.next_position;
 
-        state.current_position = start_position + 5;
+        state.current_position = start_position + input.len();
 
         let label = state.lex::<StringLiteral>()?.map(|lit| {
             state.current_position = lit.end();
@@ -28,30 +30,13 @@ impl TokenParser for CheckDeclaration {
         });
         state.current_position = state.try_lex::<ColonSymbol>()?.end();
 
-        let mut body = Vec::new();
+        let (body, end) =
+            state.lex_block(start_position, |symbol| matches!(symbol, SymbolStatement::End(..)))?;
 
-        let mut end_position = None;
-
-        while let Some(stmt) = state.lex::<Statement>()? {
-            state.current_position = stmt.end();
-
-            if let Statement::Symbol(SymbolStatement::End(end_symbol)) = &stmt {
-                end_position = Some(end_symbol.end());
-
-                break;
-            }
-
-            body.push(stmt);
-        }
-
-        if let Some(end_position) = end_position {
-            Ok(Self {
-                span: (start_position, end_position),
-                label,
-                body,
-            })
-        } else {
-            todo!("Handle unclosed block expressions")
-        }
+        Ok(Self {
+            span: (start_position, end.end()),
+            label,
+            body,
+        })
     }
 }
@@ -0,0 +1,154 @@
+use crate::{
+    ast::{
+        ColonSymbol, ExpressionStatement, OtherwiseSymbol, Statement, SymbolStatement, ThenSymbol,
+    },
+    prelude::*,
+};
+
+#[common]
+pub struct ConditionalBranch {
+    pub condition: ExpressionStatement,
+    pub body: Vec<Statement>,
+}
+
+/// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3aif-expr)>
+#[common]
+#[derive(Leaf)]
+#[regex(r"if")]
+pub struct IfExpression {
+    span: (usize, usize),
+    pub branches: Vec<ConditionalBranch>,
+    pub else_body: Option<Vec<Statement>>,
+}
+
+impl TokenParser for IfExpression {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        let start_position = state.next_position;
+
+        state.current_position = start_position + 2;
+
+        let mut branches = Vec::new();
+        let mut else_body = None;
+
+        loop {
+            let condition = state.try_lex::<ExpressionStatement>()?;
+
+            state.current_position = state.try_lex::<ColonSymbol>()?.end();
+
+            let (body, closing) = state.lex_block(start_position, |symbol| {
+                matches!(
+                    symbol,
+                    SymbolStatement::End(..)
+                        | SymbolStatement::ElseIf(..)
+                        | SymbolStatement::Else(..)
+                )
+            })?;
+
+            throw_if_empty(&body, &closing, state);
+
+            branches.push(ConditionalBranch { condition, body });
+
+            match closing {
+                SymbolStatement::ElseIf(..) => continue,
+                SymbolStatement::Else(..) => {
+                    let (body, closing) =
+                        state.lex_block(start_position, |symbol| matches!(symbol, SymbolStatement::End(..)))?;
+
+                    throw_if_empty(&body, &closing, state);
+
+                    else_body = Some(body);
+                }
+                _ => {}
+            }
+
+            break;
+        }
+
+        Ok(Self {
+            span: (start_position, state.current_position),
+            branches,
+            else_body,
+        })
+    }
+}
+
+/// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3aask-expr)>
+#[common]
+#[derive(Leaf)]
+#[regex(r"ask:")]
+pub struct AskExpression {
+    span: (usize, usize),
+    pub branches: Vec<ConditionalBranch>,
+    pub otherwise: Option<Vec<Statement>>,
+}
+
+impl TokenParser for AskExpression {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        let start_position = state.next_position;
+
+        state.current_position = start_position + 4;
+
+        let mut branches = Vec::new();
+        let mut otherwise = None;
+
+        let mut next = state.try_lex::<SymbolStatement>()?;
+
+        loop {
+            state.current_position = next.end();
+
+            match next {
+                SymbolStatement::End(..) => break,
+                SymbolStatement::Bar(..) => {
+                    if let Some(symbol) = state.lex::<OtherwiseSymbol>()? {
+                        state.current_position = symbol.end();
+
+                        let (body, closing) =
+                            state.lex_block(start_position, |symbol| matches!(symbol, SymbolStatement::End(..)))?;
+
+                        throw_if_empty(&body, &closing, state);
+
+                        otherwise = Some(body);
+
+                        break;
+                    }
+
+                    let condition = state.try_lex::<ExpressionStatement>()?;
+
+                    state.current_position = state.try_lex::<ThenSymbol>()?.end();
+
+                    let (body, closing) = state.lex_block(start_position, |symbol| {
+                        matches!(symbol, SymbolStatement::End(..) | SymbolStatement::Bar(..))
+                    })?;
+
+                    throw_if_empty(&body, &closing, state);
+
+                    branches.push(ConditionalBranch { condition, body });
+
+                    next = closing;
+                }
+                symbol => {
+                    return Err(PyretErrorKind::Unexpected {
+                        expected: Box::from(SymbolStatement::NODE_NAME),
+                        found: symbol.serialize(),
+                    });
+                }
+            }
+        }
+
+        Ok(Self {
+            span: (start_position, state.current_position),
+            branches,
+            otherwise,
+        })
+    }
+}
+
+fn throw_if_empty(body: &[Statement], closing: &SymbolStatement, state: &mut LexerState) {
+    if body.is_empty() {
+        state.throw_late(PyretErrorKind::EmptyBlock {
+            ident: closing.span().into(),
+        });
+    }
+}
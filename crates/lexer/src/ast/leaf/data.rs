@@ -0,0 +1,131 @@
+use super::function::parse_generics;
+use crate::{
+    ast::{
+        ColonSymbol, EndSymbol, IdentifierExpression, ObjectField, OpenParenSymbol, Parameter,
+        SymbolStatement,
+    },
+    prelude::*,
+};
+
+/// <https://www.pyret.org/docs/latest/s_declarations.html#(part._s~3adata-decl)>
+#[common]
+pub struct DataVariant {
+    pub ident: IdentifierExpression,
+    /// The fields of the variant, or [`None`] if it is a singleton variant.
+    pub fields: Option<Vec<Parameter>>,
+    /// The members of the `with:` section of the variant.
+    pub members: Vec<ObjectField>,
+}
+
+/// <https://www.pyret.org/docs/latest/s_declarations.html#(part._s~3adata-decl)>
+#[common]
+#[derive(Leaf)]
+#[regex(r"data")]
+pub struct DataDeclaration {
+    span: (usize, usize),
+    pub ident: IdentifierExpression,
+    pub generics: Vec<IdentifierExpression>,
+    pub variants: Vec<DataVariant>,
+    /// The members of the `sharing:` section, shared by all variants.
+    pub shared: Vec<ObjectField>,
+}
+
+impl TokenParser for DataDeclaration {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        let start_position = state.next_position;
+
+        state.current_position = start_position + 4;
+
+        let ident = state.try_lex::<IdentifierExpression>()?;
+
+        state.current_position = ident.end();
+
+        let generics = parse_generics(state)?;
+
+        state.current_position = state.try_lex::<ColonSymbol>()?.end();
+
+        let mut variants = Vec::new();
+        let mut shared = Vec::new();
+
+        loop {
+            // The bar before the first variant may be omitted.
+            let symbol = if variants.is_empty() {
+                state.lex::<SymbolStatement>()?
+            } else {
+                Some(state.try_lex::<SymbolStatement>()?)
+            };
+
+            match symbol {
+                Some(SymbolStatement::End(end)) => {
+                    state.current_position = end.end();
+
+                    break;
+                }
+                Some(SymbolStatement::Bar(bar)) => {
+                    state.current_position = bar.end();
+                }
+                Some(SymbolStatement::Sharing(sharing)) if !variants.is_empty() => {
+                    state.current_position = sharing.end();
+
+                    shared = ObjectField::parse_list(state)?;
+
+                    state.current_position = state.try_lex::<EndSymbol>()?.end();
+
+                    break;
+                }
+                Some(symbol) => {
+                    return Err(PyretErrorKind::Unexpected {
+                        expected: Box::from(SymbolStatement::NODE_NAME),
+                        found: symbol.serialize(),
+                    });
+                }
+                None => {}
+            }
+
+            variants.push(parse_variant(state)?);
+        }
+
+        if variants.is_empty() {
+            state.throw_late(PyretErrorKind::EmptyBlock {
+                ident: ident.span().into(),
+            });
+        }
+
+        Ok(Self {
+            span: (start_position, state.current_position),
+            ident,
+            generics,
+            variants,
+            shared,
+        })
+    }
+}
+
+fn parse_variant(state: &mut LexerState) -> PyretResult<DataVariant> {
+    let ident = state.try_lex::<IdentifierExpression>()?;
+
+    state.current_position = ident.end();
+
+    let fields = if let Some(open_paren) = state.lex::<OpenParenSymbol>()? {
+        state.current_position = open_paren.end();
+
+        Some(Parameter::parse_fields(state)?)
+    } else {
+        None
+    };
+
+    let members = if let Some(SymbolStatement::With(with)) = state.lex::<SymbolStatement>()? {
+        state.current_position = with.end();
+
+        ObjectField::parse_list(state)?
+    } else {
+        Vec::new()
+    };
+
+    Ok(DataVariant {
+        ident,
+        fields,
+        members,
+    })
+}
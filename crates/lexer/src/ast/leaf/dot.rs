@@ -9,8 +9,8 @@ use crate::{
 #[regex(r"\.")]
 pub struct DotExpression {
     span: (usize, usize),
-    object: Box<ExpressionStatement>,
-    property: IdentifierExpression,
+    pub object: Box<ExpressionStatement>,
+    pub property: IdentifierExpression,
 }
 
 impl TokenParser for DotExpression {
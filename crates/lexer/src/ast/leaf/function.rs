@@ -1,88 +1,265 @@
-use crate::{ast::Statement, prelude::*};
+use crate::{
+    ast::{
+        CloseParenSymbol, ColonSymbol, CommaSymbol, DocSymbol, IdentifierExpression,
+        LeftAngleSymbol, OpenParenSymbol, RefSymbol, RightAngleSymbol, ShadowSymbol, Statement,
+        StringLiteral, SymbolStatement, ThinArrowSymbol, TypeAnnotation,
+    },
+    prelude::*,
+};
 
 #[common]
 pub struct Function {
+    pub generics: Vec<IdentifierExpression>,
     pub params: Vec<Parameter>,
+    pub return_annotation: Option<TypeAnnotation>,
+    pub doc: Option<StringLiteral>,
     pub body: Vec<Statement>,
 }
 
 #[common]
 pub struct Parameter {
-    pub name: Box<str>,
-    pub type_ann: Option<Box<str>>,
+    pub ident: IdentifierExpression,
+    pub annotation: Option<TypeAnnotation>,
+    /// Whether this is a `ref` field of a data variant.
+    pub is_ref: bool,
+    /// Whether this is marked with `shadow`, allowing it to shadow an
+    /// earlier declaration of the same name.
+    pub shadows: bool,
+}
+
+impl Parameter {
+    /// Parses a comma-separated list of parameters, starting right after the
+    /// opening `(` and ending after the closing `)`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if a parameter is not valid.
+    pub fn parse_list(state: &mut LexerState) -> PyretResult<Vec<Self>> {
+        Self::parse_list_with(state, false)
+    }
+
+    /// Parses the fields of a data variant, which are parameters that may be
+    /// marked as `ref`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if a field is not valid.
+    pub fn parse_fields(state: &mut LexerState) -> PyretResult<Vec<Self>> {
+        Self::parse_list_with(state, true)
+    }
+
+    fn parse_list_with(state: &mut LexerState, allow_ref: bool) -> PyretResult<Vec<Self>> {
+        let mut params = Vec::new();
+
+        if let Some(closing) = state.lex::<CloseParenSymbol>()? {
+            state.current_position = closing.end();
+
+            return Ok(params);
+        }
+
+        loop {
+            let is_ref = allow_ref && {
+                let ref_symbol = state.lex::<RefSymbol>()?;
+
+                if let Some(ref_symbol) = &ref_symbol {
+                    state.current_position = ref_symbol.end();
+                }
+
+                ref_symbol.is_some()
+            };
+
+            let shadows = {
+                let shadow_symbol = state.lex::<ShadowSymbol>()?;
+
+                if let Some(shadow_symbol) = &shadow_symbol {
+                    state.current_position = shadow_symbol.end();
+                }
+
+                shadow_symbol.is_some()
+            };
+
+            let ident = state.try_lex::<IdentifierExpression>()?;
+            let annotation = state.lex::<TypeAnnotation>()?;
+
+            params.push(Self {
+                ident,
+                annotation,
+                is_ref,
+                shadows,
+            });
+
+            if let Some(comma) = state.lex::<CommaSymbol>()? {
+                state.current_position = comma.end();
+            } else {
+                state.current_position = state.try_lex::<CloseParenSymbol>()?.end();
+
+                break;
+            }
+        }
+
+        Ok(params)
+    }
+}
+
+impl Function {
+    /// Parses a function header and body, starting right after the name of the
+    /// function and ending after its closing `end`, where `start_position` is
+    /// the start of the keyword that opened it.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the function is not valid.
+    pub fn parse(start_position: usize, state: &mut LexerState) -> PyretResult<Self> {
+        Self::parse_with(start_position, state, |symbol| {
+            matches!(symbol, SymbolStatement::End(..))
+        })
+            .map(|(function, _)| function)
+    }
+
+    /// Parses a function like [`Function::parse`], along with the body of an
+    /// optional `where:` block before its closing `end`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the function is not valid.
+    pub fn parse_with_where(
+        start_position: usize,
+        state: &mut LexerState,
+    ) -> PyretResult<(Self, Option<Vec<Statement>>)> {
+        let (function, closing) = Self::parse_with(start_position, state, |symbol| {
+            matches!(symbol, SymbolStatement::End(..) | SymbolStatement::Where(..))
+        })?;
+
+        let where_block = if let SymbolStatement::Where(..) = closing {
+            let (body, _) = state.lex_block(start_position, |symbol| {
+                matches!(symbol, SymbolStatement::End(..))
+            })?;
+
+            Some(body)
+        } else {
+            None
+        };
+
+        Ok((function, where_block))
+    }
+
+    /// Parses the header and body of a curly-brace lambda, starting right after
+    /// the opening `{` and ending after the closing `}`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the function is not valid.
+    pub fn parse_curly(start_position: usize, state: &mut LexerState) -> PyretResult<Self> {
+        Self::parse_with(start_position, state, |symbol| {
+            matches!(symbol, SymbolStatement::CloseBrace(..))
+        })
+        .map(|(function, _)| function)
+    }
+
+    fn parse_with(
+        start_position: usize,
+        state: &mut LexerState,
+        is_closing: fn(&SymbolStatement) -> bool,
+    ) -> PyretResult<(Self, SymbolStatement)> {
+        let generics = parse_generics(state)?;
+
+        state.current_position = state.try_lex::<OpenParenSymbol>()?.end();
+
+        let params = Parameter::parse_list(state)?;
+
+        let return_annotation = if let Some(arrow) = state.lex::<ThinArrowSymbol>()? {
+            state.current_position = arrow.end();
+
+            Some(TypeAnnotation::parse(arrow.start(), state)?)
+        } else {
+            None
+        };
+
+        state.current_position = state.try_lex::<ColonSymbol>()?.end();
+
+        let doc = if let Some(doc) = state.lex::<DocSymbol>()? {
+            state.current_position = doc.end();
+
+            let string = state.try_lex::<StringLiteral>()?;
+
+            state.current_position = string.end();
+
+            Some(string)
+        } else {
+            None
+        };
+
+        let (body, closing) = state.lex_block(start_position, is_closing)?;
+
+        Ok((
+            Self {
+                generics,
+                params,
+                return_annotation,
+                doc,
+                body,
+            },
+            closing,
+        ))
+    }
+}
+
+pub(crate) fn parse_generics(state: &mut LexerState) -> PyretResult<Vec<IdentifierExpression>> {
+    let mut generics = Vec::new();
+
+    if let Some(left_angle) = state.lex::<LeftAngleSymbol>()? {
+        state.current_position = left_angle.end();
+
+        loop {
+            generics.push(state.try_lex::<IdentifierExpression>()?);
+
+            if let Some(comma) = state.lex::<CommaSymbol>()? {
+                state.current_position = comma.end();
+            } else {
+                state.current_position = state.try_lex::<RightAngleSymbol>()?.end();
+
+                break;
+            }
+        }
+    }
+
+    Ok(generics)
 }
 
 /// <https://www.pyret.org/docs/latest/s_declarations.html#(part._s~3afun-decl)>
 #[common]
 #[derive(Leaf)]
-// #[regex(r".")]
+#[regex(r"fun")]
 pub struct FunctionDeclaration {
     span: (usize, usize),
-    pub ident: Box<str>,
+    pub ident: IdentifierExpression,
     pub function: Function,
+    /// <https://www.pyret.org/docs/latest/testing.html#(part._where__blocks)>
+    pub where_block: Option<Vec<Statement>>,
 }
 
 impl TokenParser for FunctionDeclaration {
     #[inline]
     fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
-        // let name = input.name("name").unwrap().as_str().to_owned();
-
-        // self.eat(get_length(input.name("capture")));
-
-        // let mut params = Vec::new();
-
-        // for param in captures
-        //     .name("params")
-        //     .unwrap()
-        //     .as_str()
-        //     .split_terminator(',')
-        // {
-        //     let param: Vec<String> = param
-        //         .split_terminator("::")
-        //         .map(|str| str.trim().to_owned())
-        //         .collect();
-
-        //     let name = param[0].to_owned();
-        //     if !name.is_empty() {
-        //         let token = Parameter {
-        //             name,
-        //             type_ann: param.get(1).map(|type_ann| type_ann.to_owned()),
-        //         };
-
-        //         params.push(token);
-        //     };
-        // }
-
-        // let body = self.next_block()?;
-
-        // if body.is_empty() {
-        //     self.throw(LexerError::EmptyBlock, start + 4, start + name.len() + 3)?
-        // }
-
-        // {
-        //     for (i, stmt) in body.iter().enumerate() {
-        //         let last = i == body.len() - 1;
-
-        //         match stmt {
-        //             Statement::Declaration(decl) if last => todo!("no decl at
-        // end:\n{:#?}", *decl),             Statement::Expression(expr) if
-        // !last => match expr {                 ast::Expr::Ident(..) |
-        // ast::Expr::Lit(..) => {                     todo!("no standalone
-        // expr:\n{expr:#?}")                 }
-        //                 _ => {}
-        //             },
-        //             _ => {}
-        //         }
-        //     }
-        // }
+        let start_position = state.next_position;
+
+        state.current_position = start_position + 3;
+
+        let ident = state.try_lex::<IdentifierExpression>()?;
+
+        let (function, where_block) = Function::parse_with_where(start_position, state)?;
+
+        if function.body.is_empty() {
+            state.throw_late(PyretErrorKind::EmptyBlock {
+                ident: ident.span().into(),
+            });
+        }
 
         Ok(Self {
-            span: state.spanned(0),
-            ident: Box::from("mock-fn"),
-            function: Function {
-                params: vec![],
-                body: vec![],
-            },
+            span: (start_position, state.current_position),
+            ident,
+            function,
+            where_block,
         })
     }
 }
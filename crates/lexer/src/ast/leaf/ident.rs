@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use crate::{ast::EndSymbol, error::SerializedToken, prelude::*};
 
 /// <https://www.pyret.org/docs/latest/s_literals.html#(part._.Names)>
 #[common]
@@ -14,6 +14,18 @@ impl TokenParser for IdentifierExpression {
     fn parse_token(input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
         let start_position = state.next_position;
 
+        // `end` closes blocks, so it is never a name, even where only an
+        // expression is expected, like the right side of `x + end`.
+        if &*input == "end" {
+            return Err(PyretErrorKind::Unexpected {
+                expected: Box::from(Self::NODE_NAME),
+                found: SerializedToken {
+                    name: Box::from(EndSymbol::NODE_NAME),
+                    span: start_position..start_position + input.len(),
+                },
+            });
+        }
+
         state.current_position = start_position + input.len();
 
         Ok(Self {
@@ -1,6 +1,10 @@
 use super::{IdentifierExpression, StringLiteral};
 use crate::{
-    ast::{ExpressionStatement, LiteralExpression, Statement, SymbolStatement},
+    ast::{
+        ApplicationExpression, ExpressionStatement, LiteralExpression, Statement,
+        SymbolStatement,
+    },
+    error::SerializedToken,
     prelude::*,
 };
 
@@ -30,6 +34,15 @@ pub enum ImportSource {
     },
 }
 
+impl ImportSource {
+    fn serialize(&self) -> SerializedToken {
+        match self {
+            Self::Special { name, .. } | Self::Name { value: name } => name.serialize(),
+            Self::String { value } => value.serialize(),
+        }
+    }
+}
+
 /// <https://www.pyret.org/docs/latest/Import_Statements.html>
 #[common]
 #[derive(Leaf)]
@@ -67,21 +80,28 @@ fn parse_import(mut sources: Vec<ImportSource>, state: &mut LexerState) -> Pyret
     }
 
     if let Statement::Symbol(SymbolStatement::As(..)) = stmt {
+        let Some(source) = sources.pop() else {
+            return Err(PyretErrorKind::Unexpected {
+                expected: Box::from("ImportSource"),
+                found: stmt.serialize(),
+            });
+        };
+
         let name = state.try_lex()?;
 
-        return Ok(Import::As {
-            source: sources.pop().unwrap(),
-            name,
-        });
+        return Ok(Import::As { source, name });
     }
     if let Statement::Symbol(SymbolStatement::From(..)) = stmt {
         let names = sources
             .into_iter()
             .map(|source| match source {
-                ImportSource::Name { value } => value,
-                _ => todo!("expected name"),
+                ImportSource::Name { value } => Ok(value),
+                source => Err(PyretErrorKind::Unexpected {
+                    expected: Box::from(IdentifierExpression::NODE_NAME),
+                    found: source.serialize(),
+                }),
             })
-            .collect::<Vec<_>>();
+            .collect::<PyretResult<Vec<_>>>()?;
 
         let source = parse_source(state.try_lex()?)?;
 
@@ -98,18 +118,31 @@ fn parse_import(mut sources: Vec<ImportSource>, state: &mut LexerState) -> Pyret
 
 fn parse_source(stmt: Statement) -> PyretResult<ImportSource> {
     Ok(match stmt {
-        Statement::Expression(ExpressionStatement::Application(app)) => {
-            let values = app
-                .args
+        Statement::Expression(ExpressionStatement::Application(ApplicationExpression {
+            callee,
+            args,
+            ..
+        })) => {
+            let ExpressionStatement::Identifier(name) = *callee else {
+                return Err(PyretErrorKind::Unexpected {
+                    expected: Box::from(IdentifierExpression::NODE_NAME),
+                    found: callee.serialize(),
+                });
+            };
+
+            let values = args
                 .into_iter()
                 .map(|arg| match arg {
                     ExpressionStatement::Literal(LiteralExpression::String(value)) => Ok(value),
-                    _ => todo!("expected string literal"),
+                    arg => Err(PyretErrorKind::Unexpected {
+                        expected: Box::from(StringLiteral::NODE_NAME),
+                        found: arg.serialize(),
+                    }),
                 })
                 .collect::<PyretResult<Vec<_>>>()?;
 
             ImportSource::Special {
-                name: app.ident,
+                name,
                 values: Box::from_iter(values),
             }
         }
@@ -119,6 +152,11 @@ fn parse_source(stmt: Statement) -> PyretResult<ImportSource> {
         Statement::Expression(ExpressionStatement::Literal(LiteralExpression::String(value))) => {
             ImportSource::String { value }
         }
-        _ => todo!("expected import source"),
+        stmt => {
+            return Err(PyretErrorKind::Unexpected {
+                expected: Box::from("ImportSource"),
+                found: stmt.serialize(),
+            })
+        }
     })
 }
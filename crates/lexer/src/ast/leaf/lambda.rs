@@ -0,0 +1,70 @@
+use crate::{ast::Function, prelude::*};
+
+/// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3alam-expr)>
+#[common]
+#[derive(Leaf)]
+#[regex(r"lam")]
+pub struct LambdaExpression {
+    span: (usize, usize),
+    pub function: Function,
+}
+
+impl LambdaExpression {
+    #[must_use]
+    pub fn new(span: (usize, usize), function: Function) -> Self {
+        Self { span, function }
+    }
+}
+
+impl TokenParser for LambdaExpression {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        let start_position = state.next_position;
+
+        state.current_position = start_position + 3;
+
+        let function = Function::parse(start_position, state)?;
+
+        if function.body.is_empty() {
+            state.throw_late(PyretErrorKind::EmptyBlock {
+                ident: (start_position..start_position + 3).into(),
+            });
+        }
+
+        Ok(Self {
+            span: (start_position, state.current_position),
+            function,
+        })
+    }
+}
+
+/// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3acurly-lam-expr)>
+#[common]
+#[derive(Leaf)]
+#[regex(r"\{\s*\(")]
+pub struct CurlyLambdaExpression {
+    span: (usize, usize),
+    pub function: Function,
+}
+
+impl TokenParser for CurlyLambdaExpression {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        let start_position = state.next_position;
+
+        state.current_position = start_position + 1;
+
+        let function = Function::parse_curly(start_position, state)?;
+
+        if function.body.is_empty() {
+            state.throw_late(PyretErrorKind::EmptyBlock {
+                ident: (start_position..start_position + 1).into(),
+            });
+        }
+
+        Ok(Self {
+            span: (start_position, state.current_position),
+            function,
+        })
+    }
+}
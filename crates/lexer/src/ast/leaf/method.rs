@@ -0,0 +1,77 @@
+use std::ops::Range;
+
+use crate::{
+    ast::{Function, IdentifierExpression, MethodSymbol},
+    prelude::*,
+};
+
+/// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3amethod-expr)>
+#[common]
+#[derive(Leaf)]
+#[regex(r"method")]
+pub struct MethodExpression {
+    span: (usize, usize),
+    pub function: Function,
+}
+
+impl MethodExpression {
+    /// Parses a named method member, as in `method name(self): ... end`,
+    /// if there is one.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the method is not valid.
+    pub fn parse_field(
+        state: &mut LexerState,
+    ) -> PyretResult<Option<(IdentifierExpression, Self)>> {
+        let Some(symbol) = state.lex::<MethodSymbol>()? else {
+            return Ok(None);
+        };
+
+        state.current_position = symbol.end();
+
+        let ident = state.try_lex::<IdentifierExpression>()?;
+
+        state.current_position = ident.end();
+
+        let method = Self::parse(symbol.start(), ident.span(), state)?;
+
+        Ok(Some((ident, method)))
+    }
+
+    fn parse(
+        start_position: usize,
+        name: Range<usize>,
+        state: &mut LexerState,
+    ) -> PyretResult<Self> {
+        let function = Function::parse(start_position, state)?;
+
+        if function.params.is_empty() {
+            state.throw_late(PyretErrorKind::MissingSelf {
+                method: name.clone().into(),
+            });
+        }
+
+        if function.body.is_empty() {
+            state.throw_late(PyretErrorKind::EmptyBlock {
+                ident: name.into(),
+            });
+        }
+
+        Ok(Self {
+            span: (start_position, state.current_position),
+            function,
+        })
+    }
+}
+
+impl TokenParser for MethodExpression {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        let start_position = state.next_position;
+
+        state.current_position = start_position + 6;
+
+        Self::parse(start_position, start_position..state.current_position, state)
+    }
+}
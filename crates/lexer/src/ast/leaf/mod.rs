@@ -1,18 +1,26 @@
 crate::export![
     app,
+    assign,
+    bang,
     binary_op,
     block,
     boolean,
+    cases,
     check,
+    conditional,
+    data,
     dot,
     function,
     ident,
     import,
+    lambda,
+    method,
     number,
+    object,
     parenthesis,
     provide,
-    // record,
     string,
+    tuple,
     r#type,
     variable
 ];
@@ -0,0 +1,106 @@
+use crate::{
+    ast::{
+        CloseBraceSymbol, ColonSymbol, ExpressionStatement, IdentifierExpression,
+        MethodExpression, RefSymbol, SymbolStatement,
+    },
+    prelude::*,
+};
+
+#[common]
+pub struct ObjectField {
+    pub key: IdentifierExpression,
+    pub value: ExpressionStatement,
+    /// Whether this is a `ref` field, which can be updated with `!{...}`.
+    pub is_ref: bool,
+}
+
+impl ObjectField {
+    /// Parses a field, either as `key: value`, `ref key: value` or as a method
+    /// member.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the field is not valid.
+    pub fn parse(state: &mut LexerState) -> PyretResult<Self> {
+        if let Some((key, method)) = MethodExpression::parse_field(state)? {
+            return Ok(Self {
+                key,
+                value: ExpressionStatement::Method(method),
+                is_ref: false,
+            });
+        }
+
+        let ref_symbol = state.lex::<RefSymbol>()?;
+
+        if let Some(ref_symbol) = &ref_symbol {
+            state.current_position = ref_symbol.end();
+        }
+
+        let key = state.try_lex::<IdentifierExpression>()?;
+
+        state.current_position = state.try_lex::<ColonSymbol>()?.end();
+
+        let value = state.try_lex::<ExpressionStatement>()?;
+
+        state.current_position = value.end();
+
+        Ok(Self {
+            key,
+            value,
+            is_ref: ref_symbol.is_some(),
+        })
+    }
+
+    /// Parses a comma-separated list of at least one field.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if a field is not valid.
+    pub fn parse_list(state: &mut LexerState) -> PyretResult<Vec<Self>> {
+        let mut fields = vec![Self::parse(state)?];
+
+        while let Some(SymbolStatement::Comma(comma)) = state.lex::<SymbolStatement>()? {
+            state.current_position = comma.end();
+
+            fields.push(Self::parse(state)?);
+        }
+
+        Ok(fields)
+    }
+}
+
+/// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3aobj-expr)>
+#[common]
+#[derive(Leaf)]
+#[regex(r"\{\s*(\}|method\s|ref\s|[_[:alpha:]][[:word:]]*(-+[[:word:]]+)*\s*:)")]
+pub struct ObjectExpression {
+    span: (usize, usize),
+    pub fields: Vec<ObjectField>,
+}
+
+impl TokenParser for ObjectExpression {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        let start_position = state.next_position;
+
+        state.current_position = start_position + 1;
+
+        if let Some(SymbolStatement::CloseBrace(close_brace)) = state.lex::<SymbolStatement>()? {
+            state.current_position = close_brace.end();
+
+            return Ok(Self {
+                span: (start_position, state.current_position),
+                fields: Vec::new(),
+            });
+        }
+
+        let fields = ObjectField::parse_list(state)?;
+
+        state.current_position = state.try_close::<CloseBraceSymbol>(start_position)?.end();
+
+        Ok(Self {
+            span: (start_position, state.current_position),
+            fields,
+        })
+    }
+}
@@ -23,13 +23,7 @@ impl TokenParser for ParenthesisExpression {
 
         state.current_position = expr.end();
 
-        let end = if let Some(closing) = state.lex::<CloseParenSymbol>()? {
-            closing.end()
-        } else {
-            return Err(PyretErrorKind::UnclosedParenthesis {
-                position: (start_position, start_position + 1).into(),
-            });
-        };
+        let end = state.try_close::<CloseParenSymbol>(start_position)?.end();
 
         Ok(Self {
             span: (start_position, end),
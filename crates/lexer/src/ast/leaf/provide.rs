@@ -38,51 +38,56 @@ impl TokenParser for ProvideStatement {
     fn parse_token(input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
         let start_position = state.current_position;
 
-        let mut end_position = None;
-
-        let value = if input.starts_with("provide-types") {
-            end_position = Some(state.next_position + input.len());
-
-            Provide::Types(ProvideTypes::Wildcard)
+        let (value, end_position) = if input.starts_with("provide-types") {
+            (
+                Provide::Types(ProvideTypes::Wildcard),
+                state.next_position + input.len(),
+            )
         } else if input.len() > 7 {
-            end_position = Some(state.next_position + input.len());
-
-            Provide::Values(ProvideValues::Wildcard)
+            (
+                Provide::Values(ProvideValues::Wildcard),
+                state.next_position + input.len(),
+            )
         } else {
+            let opener = state.next_position;
+
             state.current_position = start_position + 8;
 
             let mut idents = HashMap::new();
 
-            while let Some(stmt) = state.lex::<Statement>()? {
-                state.current_position = stmt.end();
+            // The names are separated by commas, which are not statements of
+            // a block, so they are lexed here instead of with `lex_block`.
+            let end = loop {
+                let Some(stmt) = state.lex::<Statement>()? else {
+                    return Err(state.unclosed(opener));
+                };
 
-                if let Statement::Symbol(SymbolStatement::Comma(..)) = stmt {
-                    continue;
-                }
-
-                if let Statement::Symbol(SymbolStatement::End(..)) = &stmt {
-                    end_position = Some(state.current_position);
-
-                    break;
-                }
+                state.current_position = stmt.end();
 
-                if let Statement::Expression(ExpressionStatement::Identifier(ident)) = stmt {
-                    idents.insert(ident.name.clone(), ident);
-                } else {
-                    todo!("provide statement")
+                match stmt {
+                    Statement::Symbol(SymbolStatement::End(end)) => break end,
+                    Statement::Symbol(SymbolStatement::Comma(..)) => {}
+                    Statement::Expression(ExpressionStatement::Identifier(ident)) => {
+                        idents.insert(ident.name.clone(), ident);
+                    }
+                    stmt => {
+                        return Err(PyretErrorKind::Unexpected {
+                            expected: Box::from(IdentifierExpression::NODE_NAME),
+                            found: stmt.serialize(),
+                        });
+                    }
                 }
-            }
+            };
 
-            Provide::Values(ProvideValues::Identifiers(idents))
+            (
+                Provide::Values(ProvideValues::Identifiers(idents)),
+                end.end(),
+            )
         };
 
-        if let Some(end_position) = end_position {
-            Ok(Self {
-                span: (start_position, end_position),
-                value,
-            })
-        } else {
-            todo!("Handle unclosed block expressions")
-        }
+        Ok(Self {
+            span: (start_position, end_position),
+            value,
+        })
     }
 }
@@ -0,0 +1,101 @@
+use crate::{
+    ast::{CloseBraceSymbol, ExpressionStatement, NumericLiteral, Statement, SymbolStatement},
+    prelude::*,
+};
+
+/// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3atuple-expr)>
+#[common]
+#[derive(Leaf)]
+#[regex(r"\{")]
+pub struct TupleExpression {
+    span: (usize, usize),
+    pub elements: Vec<ExpressionStatement>,
+}
+
+impl TokenParser for TupleExpression {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        let start_position = state.next_position;
+
+        state.current_position = start_position + 1;
+
+        let mut elements = vec![parse_element(state)?];
+
+        while let Some(SymbolStatement::Semicolon(semicolon)) = state.lex::<SymbolStatement>()? {
+            state.current_position = semicolon.end();
+
+            // Allow a trailing semicolon.
+            if state.lex::<CloseBraceSymbol>()?.is_some() {
+                break;
+            }
+
+            elements.push(parse_element(state)?);
+        }
+
+        state.current_position = state.try_close::<CloseBraceSymbol>(start_position)?.end();
+
+        Ok(Self {
+            span: (start_position, state.current_position),
+            elements,
+        })
+    }
+}
+
+fn parse_element(state: &mut LexerState) -> PyretResult<ExpressionStatement> {
+    let element = state.try_lex::<ExpressionStatement>()?;
+
+    state.current_position = element.end();
+
+    Ok(element)
+}
+
+/// <https://www.pyret.org/docs/latest/Expressions.html#(part._s~3atuple-get)>
+#[common]
+#[derive(Leaf)]
+#[regex(r"\.\{")]
+pub struct TupleGetExpression {
+    span: (usize, usize),
+    pub tuple: Box<ExpressionStatement>,
+    pub index: usize,
+}
+
+impl TokenParser for TupleGetExpression {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        let start_position = state.next_position;
+
+        // Skip ".{"
+        state.current_position = start_position + 2;
+
+        let number = state.try_lex::<NumericLiteral>()?;
+
+        let index = match state.source[number.start()..number.end()].parse() {
+            Ok(index) => index,
+            Err(..) => {
+                state.throw_late(PyretErrorKind::InvalidTupleIndex {
+                    index: number.span().into(),
+                });
+
+                0
+            }
+        };
+
+        state.current_position = number.end();
+        state.current_position = state.try_lex::<CloseBraceSymbol>()?.end();
+
+        let tuple = Box::new(match state.pop()? {
+            Statement::Expression(expr) => expr,
+            stmt => {
+                return Err(PyretErrorKind::ExpectedObject {
+                    left: stmt.serialize(),
+                });
+            }
+        });
+
+        Ok(Self {
+            span: (tuple.start(), state.current_position),
+            tuple,
+            index,
+        })
+    }
+}
@@ -1,6 +1,12 @@
 use std::collections::HashMap;
 
-use crate::{ast::IdentifierExpression, prelude::*};
+use crate::{
+    ast::{
+        CloseBraceSymbol, CloseParenSymbol, CommaSymbol, IdentifierExpression, OpenBraceSymbol,
+        OpenParenSymbol, RightAngleSymbol, SymbolStatement, ThinArrowSymbol,
+    },
+    prelude::*,
+};
 
 #[common]
 
@@ -17,7 +23,7 @@ pub enum AnnotationType {
     NameAnnotation {
         name: IdentifierAnnotation,
         /// <https://www.pyret.org/docs/latest/s_annotations.html#(part._s~3aapp-ann)>
-        parameters: Vec<IdentifierExpression>,
+        parameters: Vec<AnnotationType>,
         /// <https://www.pyret.org/docs/latest/s_annotations.html#(part._s~3apred-ann)>
         predicate: Option<IdentifierExpression>,
     },
@@ -33,6 +39,184 @@ pub enum AnnotationType {
     RecordAnnotation(HashMap<Box<str>, AnnotationType>),
 }
 
+impl AnnotationType {
+    fn parse(state: &mut LexerState) -> PyretResult<Self> {
+        if let Some(open_brace) = state.lex::<OpenBraceSymbol>()? {
+            state.current_position = open_brace.end();
+
+            return Self::parse_braces(state);
+        }
+
+        if let Some(open_paren) = state.lex::<OpenParenSymbol>()? {
+            state.current_position = open_paren.end();
+
+            return Self::parse_arrow(state);
+        }
+
+        Self::parse_name(state)
+    }
+
+    /// Parses a tuple annotation like `{Number; String}` or a record
+    /// annotation like `{x :: Number, y :: Number}`, starting right after the
+    /// opening `{` and ending after the closing `}`.
+    fn parse_braces(state: &mut LexerState) -> PyretResult<Self> {
+        if let Some(close_brace) = state.lex::<CloseBraceSymbol>()? {
+            state.current_position = close_brace.end();
+
+            return Ok(Self::RecordAnnotation(HashMap::new()));
+        }
+
+        let value = if let Some((name, annotation)) = parse_field(state)? {
+            let mut fields = HashMap::from([(name, annotation)]);
+
+            while let Some(comma) = state.lex::<CommaSymbol>()? {
+                state.current_position = comma.end();
+
+                let ident = state.try_lex::<IdentifierExpression>()?;
+
+                state.current_position = ident.end();
+
+                let annotation = state.try_lex::<TypeAnnotation>()?;
+
+                fields.insert(ident.name, annotation.value);
+            }
+
+            Self::RecordAnnotation(fields)
+        } else {
+            let mut elements = vec![Self::parse(state)?];
+
+            while let Some(SymbolStatement::Semicolon(semicolon)) = state.lex::<SymbolStatement>()? {
+                state.current_position = semicolon.end();
+
+                elements.push(Self::parse(state)?);
+            }
+
+            Self::TupleAnnotation(elements)
+        };
+
+        state.current_position = state.try_lex::<CloseBraceSymbol>()?.end();
+
+        Ok(value)
+    }
+
+    /// Parses an arrow annotation like `(Number, String -> Boolean)`, starting
+    /// right after the opening `(` and ending after the closing `)`.
+    fn parse_arrow(state: &mut LexerState) -> PyretResult<Self> {
+        let mut arguments = Vec::new();
+
+        if let Some(arrow) = state.lex::<ThinArrowSymbol>()? {
+            state.current_position = arrow.end();
+        } else {
+            arguments.push(Self::parse(state)?);
+
+            while let Some(comma) = state.lex::<CommaSymbol>()? {
+                state.current_position = comma.end();
+
+                arguments.push(Self::parse(state)?);
+            }
+
+            state.current_position = state.try_lex::<ThinArrowSymbol>()?.end();
+        }
+
+        let return_annotation = Box::new(Self::parse(state)?);
+
+        state.current_position = state.try_lex::<CloseParenSymbol>()?.end();
+
+        Ok(Self::ArrowAnnotation {
+            generics: None,
+            arguments,
+            return_annotation,
+        })
+    }
+
+    /// Parses a name like `Number` or `L.List`, along with its parameters like
+    /// `List<Number>` and a predicate like `Number%(is-even)`, which directly
+    /// follow it.
+    fn parse_name(state: &mut LexerState) -> PyretResult<Self> {
+        let ident = state.try_lex::<IdentifierExpression>()?;
+
+        state.current_position = ident.end();
+
+        let name = if follows(state, '.') {
+            let mut names = vec![ident];
+
+            while follows(state, '.') {
+                state.current_position += 1;
+
+                let ident = state.try_lex::<IdentifierExpression>()?;
+
+                state.current_position = ident.end();
+
+                names.push(ident);
+            }
+
+            IdentifierAnnotation::Dot(names)
+        } else {
+            IdentifierAnnotation::Name(ident)
+        };
+
+        let mut parameters = Vec::new();
+
+        if follows(state, '<') {
+            state.current_position += 1;
+
+            parameters.push(Self::parse(state)?);
+
+            while let Some(comma) = state.lex::<CommaSymbol>()? {
+                state.current_position = comma.end();
+
+                parameters.push(Self::parse(state)?);
+            }
+
+            state.current_position = state.try_lex::<RightAngleSymbol>()?.end();
+        }
+
+        let predicate = if follows(state, '%') {
+            state.current_position += 1;
+            state.current_position = state.try_lex::<OpenParenSymbol>()?.end();
+
+            let predicate = state.try_lex::<IdentifierExpression>()?;
+
+            state.current_position = predicate.end();
+            state.current_position = state.try_lex::<CloseParenSymbol>()?.end();
+
+            Some(predicate)
+        } else {
+            None
+        };
+
+        Ok(Self::NameAnnotation {
+            name,
+            parameters,
+            predicate,
+        })
+    }
+}
+
+/// Parses a field of a record annotation like `x :: Number`, or returns `None`
+/// without consuming anything if there is none.
+fn parse_field(state: &mut LexerState) -> PyretResult<Option<(Box<str>, AnnotationType)>> {
+    let start_position = state.current_position;
+
+    if let Some(ident) = state.lex::<IdentifierExpression>()? {
+        state.current_position = ident.end();
+
+        if let Some(annotation) = state.lex::<TypeAnnotation>()? {
+            return Ok(Some((ident.name, annotation.value)));
+        }
+    }
+
+    state.current_position = start_position;
+
+    Ok(None)
+}
+
+/// Whether the character directly follows the current position, without any
+/// whitespace in between.
+fn follows(state: &LexerState, c: char) -> bool {
+    state.source[state.current_position..].starts_with(c)
+}
+
 #[common]
 #[derive(Leaf)]
 #[regex("::")]
@@ -41,23 +225,15 @@ pub struct TypeAnnotation {
     pub value: AnnotationType,
 }
 
-impl TokenParser for TypeAnnotation {
-    #[inline]
-    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
-        state.skip(2);
-
-        let start_position = state.next_position;
-
-        let value = match state.lex::<IdentifierExpression>()? {
-            Some(ident) => AnnotationType::NameAnnotation {
-                name: IdentifierAnnotation::Name(ident),
-                parameters: vec![],
-                predicate: None,
-            },
-            None => {
-                todo!()
-            }
-        };
+impl TypeAnnotation {
+    /// Parses the annotation following a `::` or `->`, which starts at
+    /// `start_position`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the annotation is not valid.
+    pub fn parse(start_position: usize, state: &mut LexerState) -> PyretResult<Self> {
+        let value = AnnotationType::parse(state)?;
 
         Ok(Self {
             span: (start_position, state.current_position),
@@ -65,3 +241,12 @@ impl TokenParser for TypeAnnotation {
         })
     }
 }
+
+impl TokenParser for TypeAnnotation {
+    #[inline]
+    fn parse_token(_input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+        state.skip(2);
+
+        Self::parse(state.next_position, state)
+    }
+}
@@ -1,8 +1,51 @@
 use crate::{
-    ast::{ExpressionStatement, IdentifierExpression, TypeAnnotation},
+    ast::{ExpressionStatement, IdentifierExpression, TupleExpression, TypeAnnotation},
     prelude::*,
 };
 
+/// A binding that destructures a tuple, such as `{a; b}`.
+#[common]
+#[derive(Leaf)]
+pub struct TupleBinding {
+    span: (usize, usize),
+    pub bindings: Vec<LetBinding>,
+}
+
+impl TupleBinding {
+    /// Reinterprets a tuple expression on the left side of a `=` as a
+    /// binding.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if an element is not a name or
+    /// another tuple.
+    pub fn from_tuple(tuple: TupleExpression) -> PyretResult<Self> {
+        let span = (tuple.start(), tuple.end());
+
+        let bindings = tuple
+            .elements
+            .into_iter()
+            .map(|element| match element {
+                ExpressionStatement::Identifier(ident) => Ok(LetBinding::Name(ident)),
+                ExpressionStatement::Tuple(tuple) => Self::from_tuple(tuple).map(LetBinding::Tuple),
+                element => Err(PyretErrorKind::ExpectedBinding {
+                    span: element.span().into(),
+                }),
+            })
+            .collect::<PyretResult<_>>()?;
+
+        Ok(Self { span, bindings })
+    }
+}
+
+/// <https://www.pyret.org/docs/latest/Statements.html#(part._s~3alet-decl)>
+#[common]
+#[derive(Node)]
+pub enum LetBinding {
+    Name(IdentifierExpression),
+    Tuple(TupleBinding),
+}
+
 #[common]
 #[derive(PartialEq, Eq)]
 pub enum LetDeclarationKind {
@@ -16,15 +59,18 @@ pub enum LetDeclarationKind {
 pub struct LetDeclaration {
     span: (usize, usize),
     pub kind: LetDeclarationKind,
-    pub ident: IdentifierExpression,
+    pub binding: LetBinding,
     pub annotation: Option<TypeAnnotation>,
     pub init: Option<ExpressionStatement>,
+    /// Whether this is marked with `shadow`, allowing it to shadow an
+    /// earlier declaration of the same name.
+    pub shadows: bool,
 }
 
 impl LetDeclaration {
     pub fn new(
         kind: LetDeclarationKind,
-        ident: IdentifierExpression,
+        binding: LetBinding,
         annotation: Option<TypeAnnotation>,
         init: Option<ExpressionStatement>,
         state: &mut LexerState,
@@ -32,16 +78,17 @@ impl LetDeclaration {
         let start_position = state.next_position;
 
         let end = init.as_ref().map_or_else(
-            || annotation.as_ref().map_or_else(|| ident.end(), Token::end),
+            || annotation.as_ref().map_or_else(|| binding.end(), Token::end),
             Token::end,
         );
 
         Ok(Self {
             span: (start_position, end),
             kind,
-            ident,
+            binding,
             annotation,
             init,
+            shadows: false,
         })
     }
 }
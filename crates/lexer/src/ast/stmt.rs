@@ -1,8 +1,8 @@
 use super::TypeAnnotation;
 use crate::{
     ast::{
-        DeclarationStatement, EqualSymbol, ExpressionStatement, ImportStatement, LetDeclaration,
-        LetDeclarationKind, ProvideStatement, SymbolStatement,
+        DeclarationStatement, EqualSymbol, ExpressionStatement, ImportStatement, LetBinding,
+        LetDeclaration, LetDeclarationKind, ProvideStatement, SymbolStatement, TupleBinding,
     },
     prelude::*,
 };
@@ -35,27 +35,52 @@ impl Statement {
             Self::Symbol(SymbolStatement::Rec(rec)) => {
                 state.current_position = rec.end();
 
-                if let Some(Self::Declaration(DeclarationStatement::Let(mut variable))) =
-                    state.lex()?
-                {
-                    variable.kind = LetDeclarationKind::RecursiveLet;
+                match state.try_lex()? {
+                    Self::Declaration(DeclarationStatement::Let(mut variable)) => {
+                        variable.kind = LetDeclarationKind::RecursiveLet;
 
-                    Self::Declaration(DeclarationStatement::Let(variable)).transform(state)?
-                } else {
-                    todo!("rec without let")
+                        Self::Declaration(DeclarationStatement::Let(variable)).transform(state)?
+                    }
+                    stmt => {
+                        return Err(PyretErrorKind::Unexpected {
+                            expected: Box::from(LetDeclaration::NODE_NAME),
+                            found: stmt.serialize(),
+                        });
+                    }
                 }
             }
             Self::Symbol(SymbolStatement::Var(var)) => {
                 state.current_position = var.end();
 
-                if let Some(Self::Declaration(DeclarationStatement::Let(mut variable))) =
-                    state.lex()?
-                {
-                    variable.kind = LetDeclarationKind::Variable;
+                match state.try_lex()? {
+                    Self::Declaration(DeclarationStatement::Let(mut variable)) => {
+                        variable.kind = LetDeclarationKind::Variable;
 
-                    Self::Declaration(DeclarationStatement::Let(variable)).transform(state)?
-                } else {
-                    todo!("var without let")
+                        Self::Declaration(DeclarationStatement::Let(variable)).transform(state)?
+                    }
+                    stmt => {
+                        return Err(PyretErrorKind::Unexpected {
+                            expected: Box::from(LetDeclaration::NODE_NAME),
+                            found: stmt.serialize(),
+                        });
+                    }
+                }
+            }
+            Self::Symbol(SymbolStatement::Shadow(shadow)) => {
+                state.current_position = shadow.end();
+
+                match state.try_lex()? {
+                    Self::Declaration(DeclarationStatement::Let(mut variable)) => {
+                        variable.shadows = true;
+
+                        Self::Declaration(DeclarationStatement::Let(variable)).transform(state)?
+                    }
+                    stmt => {
+                        return Err(PyretErrorKind::Unexpected {
+                            expected: Box::from(LetDeclaration::NODE_NAME),
+                            found: stmt.serialize(),
+                        });
+                    }
                 }
             }
             Self::Expression(ExpressionStatement::Identifier(ident)) => {
@@ -66,7 +91,7 @@ impl Statement {
 
                     Self::Declaration(DeclarationStatement::Let(LetDeclaration::new(
                         LetDeclarationKind::Let,
-                        ident,
+                        LetBinding::Name(ident),
                         None,
                         Some(init),
                         state,
@@ -85,7 +110,7 @@ impl Statement {
 
                     Self::Declaration(DeclarationStatement::Let(LetDeclaration::new(
                         LetDeclarationKind::Let,
-                        ident,
+                        LetBinding::Name(ident),
                         Some(ann),
                         init,
                         state,
@@ -94,6 +119,24 @@ impl Statement {
                     Self::Expression(ExpressionStatement::Identifier(ident))
                 }
             }
+            Self::Expression(ExpressionStatement::Tuple(tuple)) => {
+                if let Some(equal) = state.lex::<EqualSymbol>()? {
+                    state.current_position = equal.end();
+
+                    let init = state.try_lex::<ExpressionStatement>()?;
+
+                    Self::Declaration(DeclarationStatement::Let(LetDeclaration::new(
+                        LetDeclarationKind::Let,
+                        LetBinding::Tuple(TupleBinding::from_tuple(tuple)?),
+                        None,
+                        Some(init),
+                        state,
+                    )?))
+                    .transform(state)?
+                } else {
+                    Self::Expression(ExpressionStatement::Tuple(tuple))
+                }
+            }
             _ => self,
         })
     }
@@ -7,6 +7,8 @@ pub enum SymbolStatement {
     As(AsSymbol),
     #[regex(r"from")]
     From(FromSymbol),
+    #[regex(r"=>")]
+    FatArrow(FatArrowSymbol),
     #[regex(r"=")]
     Equal(EqualSymbol),
     #[regex(r"end")]
@@ -15,10 +17,67 @@ pub enum SymbolStatement {
     Var(VarSymbol),
     #[regex(r"rec")]
     Rec(RecSymbol),
+    #[regex(r"shadow\s")]
+    Shadow(ShadowSymbol),
     #[regex(r",")]
     Comma(CommaSymbol),
+    #[regex(r";")]
+    Semicolon(SemicolonSymbol),
     #[regex(r":")]
     Colon(ColonSymbol),
     #[regex(r"\)")]
     CloseParen(CloseParenSymbol),
+    #[regex(r"\}")]
+    CloseBrace(CloseBraceSymbol),
+    #[regex(r"->")]
+    ThinArrow(ThinArrowSymbol),
+    #[regex(r"doc:")]
+    Doc(DocSymbol),
+    #[regex(r"else\s+if")]
+    ElseIf(ElseIfSymbol),
+    #[regex(r"else:")]
+    Else(ElseSymbol),
+    #[regex(r"\|")]
+    Bar(BarSymbol),
+    #[regex(r"then:")]
+    Then(ThenSymbol),
+    #[regex(r"otherwise:")]
+    Otherwise(OtherwiseSymbol),
+    #[regex(r"with:")]
+    With(WithSymbol),
+    #[regex(r"sharing:")]
+    Sharing(SharingSymbol),
+    #[regex(r"where:")]
+    Where(WhereSymbol),
 }
+
+/// A symbol that is only lexed explicitly, since it would otherwise be
+/// ambiguous with an expression or an operator.
+macro_rules! explicit_symbol {
+    ($ident:ident, $regex:literal) => {
+        #[common]
+        #[derive(Leaf)]
+        #[regex($regex)]
+        pub struct $ident {
+            span: (usize, usize),
+        }
+
+        impl TokenParser for $ident {
+            #[inline]
+            fn parse_token(input: Box<str>, state: &mut LexerState) -> PyretResult<Self> {
+                Ok(Self {
+                    span: state.spanned(input.len()),
+                })
+            }
+        }
+    };
+}
+
+explicit_symbol!(OpenParenSymbol, r"\(");
+explicit_symbol!(OpenBraceSymbol, r"\{");
+explicit_symbol!(ColonEqualSymbol, r":=");
+explicit_symbol!(LeftAngleSymbol, r"<");
+explicit_symbol!(RightAngleSymbol, r">");
+explicit_symbol!(ElseArrowSymbol, r"else\s*=>");
+explicit_symbol!(MethodSymbol, r"method\s");
+explicit_symbol!(RefSymbol, r"ref\s");
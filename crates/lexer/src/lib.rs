@@ -1,5 +1,3 @@
-#![feature(if_let_guard)]
-
 mod comments;
 mod macros;
 mod state;
@@ -12,6 +10,7 @@ mod prelude;
 extern crate pyret_lexer_macros;
 
 use comments::remove_comments;
+use error::miette::Diagnostic;
 use prelude::*;
 pub use pyret_error as error;
 pub use token::Token;
@@ -21,32 +20,74 @@ pub use token::Token;
 /// Will return a vector of [`PyretErrorKind`]s if there are any lexing errors.
 #[inline]
 pub fn lex(source: &str) -> Result<Vec<ast::Statement>, Vec<PyretErrorKind>> {
-    let source = remove_comments(source).map_err(|error| vec![error])?;
+    let (stmts, errors) = lex_partial(source);
 
-    let mut state = LexerState::new(&source);
+    if errors.is_empty() {
+        Ok(stmts)
+    } else {
+        Err(errors)
+    }
+}
 
-    let lex_state = lex_state(&mut state);
+/// Lexes a source like [`lex`], but recovers from syntax errors by skipping to
+/// the next top-level statement, so that every error in the source is
+/// reported. Returns the statements that could be lexed, for editor tooling,
+/// along with the errors in the order they appear in the source.
+#[must_use]
+pub fn lex_partial(source: &str) -> (Vec<ast::Statement>, Vec<PyretErrorKind>) {
+    let source = match remove_comments(source) {
+        Ok(source) => source,
+        Err(error) => return (Vec::new(), vec![error]),
+    };
 
-    let mut errors = state.late_errors;
+    let mut state = LexerState::new(&source);
 
-    match lex_state {
-        Ok(..) => {
-            if errors.is_empty() {
-                Ok(state.tokens)
-            } else {
-                Err(errors)
-            }
-        }
-        Err(error) => {
-            errors.push(error);
+    let mut errors = Vec::new();
+
+    while let Err(error) = lex_state(&mut state) {
+        errors.push(error);
 
-            Err(errors)
+        if !state.recover() {
+            break;
         }
     }
+
+    errors.append(&mut state.late_errors);
+    errors.sort_by_key(|error| {
+        error
+            .labels()
+            .and_then(|mut labels| labels.next())
+            .map(|label| label.offset())
+    });
+
+    (state.tokens, errors)
 }
 
 fn lex_state(state: &mut LexerState) -> PyretResult<()> {
-    while let Some(stmt) = state.lex()? {
+    loop {
+        // Operands are pushed while an expression is lexed, so they are
+        // removed again if the statement fails.
+        let consumed = state.tokens.len();
+
+        let stmt = match state.lex() {
+            Ok(Some(stmt)) => stmt,
+            Ok(None) => break,
+            Err(error) => {
+                state.tokens.truncate(consumed);
+
+                return Err(error);
+            }
+        };
+
+        if let ast::Statement::Symbol(symbol) = &stmt {
+            state.current_position = stmt.end();
+
+            return Err(PyretErrorKind::Unexpected {
+                expected: Box::from(ast::Statement::NODE_NAME),
+                found: symbol.serialize(),
+            });
+        }
+
         state.consume(stmt);
     }
 
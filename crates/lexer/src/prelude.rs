@@ -1,5 +1,5 @@
 pub use crate::{
-    error::{PyretError, PyretErrorKind, PyretFile, PyretResult, SerializedToken},
+    error::{PyretErrorKind, PyretResult},
     state::LexerState,
     token::{Token, TokenLexer, TokenParser},
 };
@@ -78,6 +78,111 @@ impl<'input> LexerState<'input> {
         }
     }
 
+    /// Lexes the symbol that closes the brackets opened at `opener`, which is
+    /// pointed at if the source ends before they are closed.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if the brackets were never closed,
+    /// or if something other than the symbol follows.
+    pub fn try_close<T: TokenLexer + 'static>(&mut self, opener: usize) -> PyretResult<T> {
+        if self.source[self.current_position..]
+            .chars()
+            .all(|c| c.is_ascii_whitespace())
+        {
+            return Err(self.unclosed(opener));
+        }
+
+        self.try_lex()
+    }
+
+    /// Lexes the statements of a block, until one of the symbols that closes
+    /// it, which is returned along with the statements. The block is opened
+    /// by the keyword at `opener`, which is pointed at if it is never closed.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`PyretErrorKind`] if there was an error parsing a
+    /// statement, if a symbol that does not close the block is found, or if
+    /// the block was never closed.
+    pub fn lex_block(
+        &mut self,
+        opener: usize,
+        is_closing: fn(&ast::SymbolStatement) -> bool,
+    ) -> PyretResult<(Vec<ast::Statement>, ast::SymbolStatement)> {
+        let mut body = Vec::new();
+
+        loop {
+            let Some(stmt) = self.lex::<ast::Statement>()? else {
+                return Err(self.unclosed(opener));
+            };
+
+            self.current_position = stmt.end();
+
+            match stmt {
+                ast::Statement::Symbol(symbol) if is_closing(&symbol) => {
+                    return Ok((body, symbol));
+                }
+                ast::Statement::Symbol(symbol) => {
+                    return Err(PyretErrorKind::Unexpected {
+                        expected: Box::from(ast::Statement::NODE_NAME),
+                        found: symbol.serialize(),
+                    });
+                }
+                stmt => body.push(stmt),
+            }
+        }
+    }
+
+    /// Skips past a statement that failed to lex, to the next line that is
+    /// not indented, along with an `end` that starts it, which likely closed
+    /// the failed statement. Returns whether there is anything left to lex.
+    pub fn recover(&mut self) -> bool {
+        let position = self.next_position.max(self.current_position);
+
+        let next_line = self.source[position..]
+            .match_indices('\n')
+            .map(|(index, _)| position + index + 1)
+            .find(|&start| self.source[start..].starts_with(|c: char| !c.is_ascii_whitespace()));
+
+        let Some(next_line) = next_line else {
+            self.current_position = self.source.len();
+
+            return false;
+        };
+
+        let rest = &self.source[next_line..];
+
+        let closes = rest.starts_with("end")
+            && !rest[3..].starts_with(|c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+        self.current_position = if closes { next_line + 3 } else { next_line };
+
+        true
+    }
+
+    /// Creates the error for a block that reached the end of the source
+    /// without being closed, pointing at the keyword or symbol at `opener`.
+    #[must_use]
+    pub fn unclosed(&self, opener: usize) -> PyretErrorKind {
+        let rest = &self.source[opener..];
+
+        let mut length = rest
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+            .unwrap_or(rest.len());
+
+        if length == 0 {
+            length = rest.chars().next().map_or(0, char::len_utf8);
+        } else if rest[length..].starts_with(':') {
+            length += 1;
+        }
+
+        PyretErrorKind::UnclosedBlock {
+            opener: Box::from(&rest[..length]),
+            span: (opener, length).into(),
+        }
+    }
+
     pub fn throw_late(&mut self, error: PyretErrorKind) {
         self.late_errors.push(error);
     }
@@ -77,8 +77,7 @@ impl Num for PyretNumber {
             } else {
                 let is_negative = str.starts_with('-');
 
-                let mut split =
-                    if is_negative { &str[1..] } else { str }.splitn(2, |c| c == 'e' || c == 'E');
+                let mut split = if is_negative { &str[1..] } else { str }.splitn(2, ['e', 'E']);
 
                 let base = split.next().ok_or(PyretNumberParseError::InvalidNumber)?;
 
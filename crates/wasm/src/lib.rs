@@ -1,12 +1,15 @@
 #![allow(clippy::use_self, clippy::new_without_default, clippy::boxed_local)]
 
-use std::{cell::RefCell, rc::Rc, sync::Arc};
+use std::{rc::Rc, sync::Arc};
 
 use js_sys::{Array, Function, Object, Reflect};
 use pyret_file::PyretFile;
 use pyret_interpreter::{
     trove,
-    value::{context::Context, PyretFunction, PyretValue, PyretValueKind},
+    value::{
+        context::Context, PyretData, PyretFunction, PyretObject, PyretRef, PyretValue,
+        PyretValueKind,
+    },
     Interpreter, PyretGraph,
 };
 use pyret_number::PyretNumber;
@@ -46,20 +49,53 @@ impl PyretRuntime {
         Self { interpreter }
     }
 
-    #[wasm_bindgen(js_name = "useContext")]
-    pub fn use_context(&mut self, context: &str) {
-        self.interpreter.import_trove(context).unwrap();
+    /// Sets which test blocks are run, as `none`, `main` or `all`.
+    #[wasm_bindgen(js_name = "setChecks")]
+    pub fn set_checks(&mut self, checks: &str) -> Result<(), JsValue> {
+        self.interpreter.check_mode = checks
+            .parse()
+            .map_err(|error: String| JsValue::from_str(&error))?;
+
+        Ok(())
+    }
+
+    /// Sets whether the types of a program are checked before it runs.
+    #[wasm_bindgen(js_name = "setTypeCheck")]
+    pub fn set_type_check(&mut self, type_check: bool) {
+        self.interpreter.type_check = type_check;
     }
 
-    pub fn run(&mut self, file_id: usize) -> Box<[JsValue]> {
-        let values = self.interpreter.interpret(file_id).unwrap();
+    #[wasm_bindgen(js_name = "useContext")]
+    pub fn use_context(&mut self, context: &str) -> Result<(), JsValue> {
+        self.interpreter
+            .import_trove(context)
+            .map_err(|error| JsValue::from_str(&error.to_string()))
+    }
 
-        values.iter().map(pyret_to_js).collect()
+    pub fn run(&mut self, file_id: usize) -> Result<Box<[JsValue]>, JsValue> {
+        let values = self.interpreter.interpret(file_id).map_err(|errors| {
+            JsValue::from_str(
+                &errors
+                    .iter()
+                    .map(|error| error.kind().to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )
+        })?;
+
+        let context = &self.interpreter.context;
+
+        Ok(values
+            .iter()
+            .map(|value| pyret_to_js(value, context))
+            .collect())
     }
 }
 
+/// Converts a value for JavaScript, where its functions are evaluated in
+/// `context`, which is the context of the runtime.
 #[must_use]
-fn pyret_to_js(value: &PyretValue) -> JsValue {
+fn pyret_to_js(value: &PyretValue, context: &Context) -> JsValue {
     let span = {
         if let Some(span) = &value.span {
             #[allow(clippy::cast_precision_loss)]
@@ -81,22 +117,74 @@ fn pyret_to_js(value: &PyretValue) -> JsValue {
         },
         PyretValueKind::String(string) => ("String", JsValue::from_str(string)),
         PyretValueKind::Boolean(boolean) => ("Boolean", JsValue::from_bool(*boolean)),
-        PyretValueKind::Function(function) => {
+        PyretValueKind::Function(function) | PyretValueKind::Method(function) => {
             let function = function.clone();
+            let call_span = value.span.clone().unwrap_or_default();
+            let context = context.clone();
 
             let closure = Closure::wrap(Box::new(move |args: Vec<JsValue>| {
                 let args = args
                     .into_iter()
-                    .map(js_to_pyret)
+                    .map(|arg| js_to_pyret(arg, &context))
                     .collect::<Vec<PyretValue>>();
 
-                let value = function.call(args, 0).unwrap();
+                // Calls from JavaScript can happen while the runtime is in the
+                // middle of a run, so they get an interpreter of their own to
+                // evaluate the body on, which shares the context of the
+                // runtime along with its troves.
+                let mut interpreter = Interpreter::new(PyretGraphWrapper);
+
+                interpreter.context = context.clone();
+
+                let value = function
+                    .call(args, call_span.clone(), 0, &mut interpreter)
+                    .map_err(|error| JsValue::from_str(&error.to_string()))?;
 
-                pyret_to_js(&value)
-            }) as Box<dyn FnMut(Vec<JsValue>) -> JsValue>);
+                Ok(pyret_to_js(&value, &context))
+            })
+                as Box<dyn FnMut(Vec<JsValue>) -> Result<JsValue, JsValue>>);
 
             ("Function", closure.into_js_value())
         }
+        PyretValueKind::Data(data) => {
+            let object = Object::new();
+
+            let fields = data.fields.as_ref().map_or(JsValue::NULL, |fields| {
+                JsValue::from(
+                    fields
+                        .iter()
+                        .map(|(name, value)| {
+                            Array::of2(&JsValue::from_str(name), &pyret_to_js(value, context))
+                        })
+                        .collect::<Array>(),
+                )
+            });
+
+            Reflect::set(&object, &"type".into(), &(*data.type_name).into()).unwrap();
+            Reflect::set(&object, &"variant".into(), &(*data.variant).into()).unwrap();
+            Reflect::set(&object, &"fields".into(), &fields).unwrap();
+
+            ("Data", JsValue::from(object))
+        }
+        PyretValueKind::Object(object) => {
+            let fields = Object::new();
+
+            for (key, value) in object.fields.iter() {
+                Reflect::set(&fields, &(**key).into(), &pyret_to_js(value, context)).unwrap();
+            }
+
+            ("Object", JsValue::from(fields))
+        }
+        PyretValueKind::Tuple(elements) => (
+            "Tuple",
+            JsValue::from(
+                elements
+                    .iter()
+                    .map(|element| pyret_to_js(element, context))
+                    .collect::<Array>(),
+            ),
+        ),
+        PyretValueKind::Ref(reference) => ("Ref", pyret_to_js(&reference.get(), context)),
         PyretValueKind::Nothing => ("Nothing", JsValue::NULL),
     };
 
@@ -109,7 +197,9 @@ fn pyret_to_js(value: &PyretValue) -> JsValue {
     JsValue::from(object)
 }
 
-fn js_to_pyret(value: JsValue) -> PyretValue {
+/// Converts a value from JavaScript, where the Pyret functions it is given are
+/// evaluated in `context`, which is the context of the runtime.
+fn js_to_pyret(value: JsValue, context: &Context) -> PyretValue {
     let object = value.dyn_into::<Object>().unwrap();
 
     #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
@@ -158,16 +248,18 @@ fn js_to_pyret(value: JsValue) -> PyretValue {
                 .into_boxed_slice();
 
             let body = Rc::new(
-                move |args: &mut dyn Iterator<Item = PyretValue>, _context| {
-                    let args = args.map(|value| pyret_to_js(&value)).collect::<Array>();
+                move |args: &mut dyn Iterator<Item = PyretValue>, context: Context| {
+                    let args = args
+                        .map(|value| pyret_to_js(&value, &context))
+                        .collect::<Array>();
 
                     let value = function.apply(&JsValue::NULL, &args).unwrap();
 
-                    Ok(js_to_pyret(value))
+                    Ok(js_to_pyret(value, &context))
                 },
             );
 
-            let context = Context::default();
+            let context = context.clone();
 
             PyretValueKind::Function(PyretFunction::new(
                 name,
@@ -178,6 +270,68 @@ fn js_to_pyret(value: JsValue) -> PyretValue {
                 context,
             ))
         }
+        "Data" => {
+            let get_string = |key: &str| {
+                Reflect::get(&value, &key.into())
+                    .unwrap()
+                    .as_string()
+                    .unwrap()
+                    .into_boxed_str()
+            };
+
+            let fields = Reflect::get(&value, &"fields".into())
+                .unwrap()
+                .dyn_into::<Array>()
+                .ok()
+                .map(|fields| {
+                    fields
+                        .iter()
+                        .map(|field| {
+                            let field = field.dyn_into::<Array>().unwrap();
+
+                            (
+                                field.get(0).as_string().unwrap().into_boxed_str(),
+                                js_to_pyret(field.get(1), context),
+                            )
+                        })
+                        .collect()
+                });
+
+            let variant = get_string("variant");
+
+            PyretValueKind::Data(PyretData::new(
+                get_string("type"),
+                variant.clone(),
+                Rc::from([variant]),
+                fields,
+                Rc::default(),
+                None,
+            ))
+        }
+        "Object" => {
+            let fields = Object::entries(&value.dyn_into::<Object>().unwrap())
+                .iter()
+                .map(|entry| {
+                    let entry = entry.dyn_into::<Array>().unwrap();
+
+                    (
+                        entry.get(0).as_string().unwrap().into_boxed_str(),
+                        js_to_pyret(entry.get(1), context),
+                    )
+                })
+                .collect();
+
+            PyretValueKind::Object(PyretObject::new(fields))
+        }
+        "Ref" => PyretValueKind::Ref(PyretRef::new(js_to_pyret(value, context), None)),
+        "Tuple" => PyretValueKind::Tuple(
+            value
+                .dyn_into::<Array>()
+                .unwrap()
+                .iter()
+                .map(|element| js_to_pyret(element, context))
+                .collect(),
+        ),
         _ => todo!(),
     };
 